@@ -16,7 +16,7 @@ fn main() {
     let date = denver.with_ymd_and_hms(2024, 3, 19, 18, 0, 0).unwrap();
     let badi_date = LocalBadiDate::from_datetime(date, coords).unwrap();
     assert_eq!(
-        LocalBadiDate::new(180, BadiMonth::Month(19), 19, denver, coords).unwrap(),
+        LocalBadiDate::new(180, BadiMonth::Ala, 19, denver, coords).unwrap(),
         badi_date,
     );
     println!("date: {:?}\nbadi_date: {:?}", date, badi_date);
@@ -36,13 +36,13 @@ fn main() {
     // Test fallback conversion (no coordinates)
     let badi_fallback = LocalBadiDate::from_datetime(date, None).unwrap();
     assert_eq!(
-        LocalBadiDate::new(181, BadiMonth::Month(1), 1, denver, None).unwrap(),
+        LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap(),
         badi_fallback,
     );
     println!("date: {:?}\nbadi_fallback: {:?}", date, badi_fallback);
 
     // Declaration of the Báb
-    let declaration = BadiDate::new(181, BadiMonth::Month(4), 8).unwrap();
+    let declaration = BadiDate::new(181, BadiMonth::Azamat, 8).unwrap();
     assert_eq!(
         declaration.holy_day(),
         Some(BahaiHolyDay::DeclarationOfTheBab),
@@ -50,11 +50,11 @@ fn main() {
 
     assert_eq!(declaration.holy_day().unwrap().work_suspended(), true);
 
-    let naw_ruz = BadiDate::new(182, BadiMonth::Month(1), 1).unwrap();
+    let naw_ruz = BadiDate::new(182, BadiMonth::Baha, 1).unwrap();
     let ascension = naw_ruz.previous_holy_day().unwrap();
     assert_eq!(
         ascension,
-        BadiDate::new(181, BadiMonth::Month(14), 6).unwrap()
+        BadiDate::new(181, BadiMonth::Qawl, 6).unwrap()
     );
     assert_eq!(
         ascension.holy_day(),
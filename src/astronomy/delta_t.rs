@@ -0,0 +1,77 @@
+/// ΔT at the start of each year 2005...2026 as observed by the IERS (the polynomial expressions for 2005...2050
+/// have overestimated it by several seconds since Earth's rotation stopped slowing around 2016)
+const OBSERVED_DELTA_T: [f64; 22] = [
+    64.69, 64.85, 65.15, 65.46, 65.78, 66.07, 66.32, 66.6, 66.91, 67.28, 67.64, 68.1, 68.59, 68.97,
+    69.22, 69.36, 69.36, 69.29, 69.2, 69.18, 69.14, 69.1,
+];
+
+/// ΔT = TT - UT in seconds for the given Gregorian year
+/// Polynomial expressions by Espenak & Meeus, _Five Millennium Canon of Solar Eclipses_ (NASA, 2006)
+/// <https://eclipse.gsfc.nasa.gov/SEhelp/deltatpoly2004.html>
+pub(crate) fn delta_t(year: i32) -> f64 {
+    // middle of the year; Naw-Rúz and the Twin Holy Days are within a few months of it
+    let y = year as f64 + 0.5;
+    match year {
+        ..1800 => {
+            let u = (y - 1820.) / 100.;
+            -20. + 32. * u * u
+        }
+        1800..1860 => {
+            let t = y - 1800.;
+            13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+                - 0.00037436 * t.powi(4)
+                + 0.0000121272 * t.powi(5)
+                - 0.0000001699 * t.powi(6)
+                + 0.000000000875 * t.powi(7)
+        }
+        1860..1900 => {
+            let t = y - 1860.;
+            7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3)
+                - 0.0004473624 * t.powi(4)
+                + t.powi(5) / 233174.
+        }
+        1900..1920 => {
+            let t = y - 1900.;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        1920..1941 => {
+            let t = y - 1920.;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        1941..1961 => {
+            let t = y - 1950.;
+            29.07 + 0.407 * t - t.powi(2) / 233. + t.powi(3) / 2547.
+        }
+        1961..1986 => {
+            let t = y - 1975.;
+            45.45 + 1.067 * t - t.powi(2) / 260. - t.powi(3) / 718.
+        }
+        1986..2005 => {
+            let t = y - 2000.;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        2005..2026 => {
+            let i = (year - 2005) as usize;
+            (OBSERVED_DELTA_T[i] + OBSERVED_DELTA_T[i + 1]) / 2.
+        }
+        2026..2050 => {
+            // from the last observation to the expression for 2050...2150
+            let u = (2050. - 1820.) / 100.;
+            let end = -20. + 32. * u * u - 0.5628 * (2150. - 2050.);
+            let last = OBSERVED_DELTA_T[OBSERVED_DELTA_T.len() - 1];
+            last + (end - last) * (y - 2026.) / 24.
+        }
+        2050..2150 => {
+            let u = (y - 1820.) / 100.;
+            -20. + 32. * u * u - 0.5628 * (2150. - y)
+        }
+        2150.. => {
+            let u = (y - 1820.) / 100.;
+            -20. + 32. * u * u
+        }
+    }
+}
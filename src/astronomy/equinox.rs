@@ -0,0 +1,86 @@
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Utc};
+
+use super::*;
+
+/// Periodic terms (A, B, C) for the equinoxes and solstices (Meeus, table 27.C)
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485., 324.96, 1934.136),
+    (203., 337.23, 32964.467),
+    (199., 342.08, 20.186),
+    (182., 27.85, 445267.112),
+    (156., 73.14, 45036.886),
+    (136., 171.52, 22518.443),
+    (77., 222.54, 65928.934),
+    (74., 296.72, 3034.906),
+    (70., 243.58, 9037.513),
+    (58., 119.81, 33718.147),
+    (52., 297.17, 150.678),
+    (50., 21.02, 2281.226),
+    (45., 247.54, 29929.562),
+    (44., 325.15, 31555.956),
+    (29., 60.93, 4443.417),
+    (18., 155.12, 67555.328),
+    (17., 288.79, 4562.452),
+    (16., 198.04, 62894.029),
+    (14., 199.76, 31436.921),
+    (12., 95.39, 14577.848),
+    (12., 287.11, 31931.756),
+    (12., 320.81, 34777.259),
+    (9., 227.73, 1222.114),
+    (8., 15.45, 16859.074),
+];
+
+/// Computes the moment (UTC) of the March (vernal) equinox of the given Gregorian year
+/// (Meeus, chapter 27, refined with the apparent longitude of the sun; accurate to several seconds,
+/// which matters when the equinox falls within minutes of sunset in Ṭihrán)
+pub(crate) fn march_equinox(year: i32) -> DateTime<Utc> {
+    let y = (year as f64 - 2000.) / 1000.;
+    let jde0 = 2451623.80984 + 365242.37404 * y + 0.05169 * y.powi(2)
+        - 0.00411 * y.powi(3)
+        - 0.00057 * y.powi(4);
+    let t = (jde0 - 2451545.0) / 36525.;
+    let w = (35999.373 * t - 2.47) * PI / 180.;
+    let delta_lambda = 1. + 0.0334 * w.cos() + 0.0007 * (2. * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (normalize_degrees(b + c * t) * PI / 180.).cos())
+        .sum();
+    let mut jde = jde0 + 0.00001 * s / delta_lambda;
+    for _ in 0..5 {
+        let correction = 58. * (-apparent_solar_longitude(jde)).to_radians().sin();
+        jde += correction;
+        if correction.abs() < 1e-7 {
+            break;
+        }
+    }
+    julian_ephemeris_day_to_utc(jde, year)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn march_equinox_matches_published_times() {
+        // USNO published times (rounded to the minute)
+        for (year, month, day, hour, minute) in [
+            (2000, 3, 20, 7, 35),
+            (2015, 3, 20, 22, 45),
+            (2024, 3, 20, 3, 6),
+            (2025, 3, 20, 9, 1),
+        ] {
+            let expected = Utc
+                .with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap();
+            let actual = march_equinox(year);
+            assert!(
+                (actual - expected).num_seconds().abs() <= 90,
+                "{year}: expected {expected}, got {actual}"
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Astronomical algorithms used to derive the Badí‘ calendar from the sky as seen in Ṭihrán.
+//! Adapted from Jean Meeus, _Astronomical Algorithms_ (2nd ed., 1998).
+
+mod delta_t;
+pub(crate) use delta_t::*;
+
+mod equinox;
+pub(crate) use equinox::*;
+
+mod moon;
+pub(crate) use moon::*;
+
+mod solar;
+pub use solar::*;
+
+mod tehran;
+pub(crate) use tehran::*;
+
+mod vsop87;
+pub(crate) use vsop87::*;
+
+use chrono::{DateTime, Utc};
+
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+const SECONDS_IN_A_DAY: f64 = 86400.;
+
+/// Converts a UTC [`DateTime`] to a Julian Day (UT)
+pub(crate) fn julian_day(moment: DateTime<Utc>) -> f64 {
+    moment.timestamp_millis() as f64 / 1000. / SECONDS_IN_A_DAY + UNIX_EPOCH_JULIAN_DAY
+}
+
+/// Converts a Julian Day (UT) to a UTC [`DateTime`], rounded to the millisecond
+pub(crate) fn julian_day_to_utc(julian_day: f64) -> DateTime<Utc> {
+    let millis = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * SECONDS_IN_A_DAY * 1000.).round();
+    DateTime::from_timestamp_millis(millis as i64).expect("Julian day out of range")
+}
+
+/// Converts a Julian Ephemeris Day (TT) to a UTC [`DateTime`] by subtracting ΔT
+pub(crate) fn julian_ephemeris_day_to_utc(julian_ephemeris_day: f64, year: i32) -> DateTime<Utc> {
+    julian_day_to_utc(julian_ephemeris_day - delta_t(year) / SECONDS_IN_A_DAY)
+}
+
+/// Reduces an angle in degrees to the range [0, 360)
+pub(crate) fn normalize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.)
+}
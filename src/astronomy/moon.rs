@@ -0,0 +1,134 @@
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Utc};
+
+use super::*;
+
+/// Mean length of the synodic month in days
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Periodic terms for the true new moon (Meeus, chapter 49): (coefficient, power of E, M, M', F, Ω)
+const NEW_MOON_TERMS: [(f64, i32, f64, f64, f64, f64); 25] = [
+    (-0.40720, 0, 0., 1., 0., 0.),
+    (0.17241, 1, 1., 0., 0., 0.),
+    (0.01608, 0, 0., 2., 0., 0.),
+    (0.01039, 0, 0., 0., 2., 0.),
+    (0.00739, 1, -1., 1., 0., 0.),
+    (-0.00514, 1, 1., 1., 0., 0.),
+    (0.00208, 2, 2., 0., 0., 0.),
+    (-0.00111, 0, 0., 1., -2., 0.),
+    (-0.00057, 0, 0., 1., 2., 0.),
+    (0.00056, 1, 1., 2., 0., 0.),
+    (-0.00042, 0, 0., 3., 0., 0.),
+    (0.00042, 1, 1., 0., 2., 0.),
+    (0.00038, 1, 1., 0., -2., 0.),
+    (-0.00024, 1, -1., 2., 0., 0.),
+    (-0.00017, 0, 0., 0., 0., 1.),
+    (-0.00007, 0, 2., 1., 0., 0.),
+    (0.00004, 0, 0., 2., -2., 0.),
+    (0.00004, 0, 3., 0., 0., 0.),
+    (0.00003, 0, 1., 1., -2., 0.),
+    (0.00003, 0, 0., 2., 2., 0.),
+    (-0.00003, 0, 1., 1., 2., 0.),
+    (0.00003, 0, -1., 1., 2., 0.),
+    (-0.00002, 0, -1., 1., -2., 0.),
+    (-0.00002, 0, 1., 3., 0., 0.),
+    (0.00002, 0, 0., 4., 0., 0.),
+];
+
+/// Planetary arguments for the additional corrections (Meeus, chapter 49): (coefficient, A0, A per lunation)
+const PLANETARY_TERMS: [(f64, f64, f64); 13] = [
+    (0.000165, 251.88, 0.016321),
+    (0.000164, 251.83, 26.651886),
+    (0.000126, 349.42, 36.412478),
+    (0.000110, 84.66, 18.206239),
+    (0.000062, 141.74, 53.303771),
+    (0.000060, 207.14, 2.453732),
+    (0.000056, 154.84, 7.306860),
+    (0.000047, 34.52, 27.261239),
+    (0.000042, 207.19, 0.121824),
+    (0.000040, 291.34, 1.844379),
+    (0.000037, 161.72, 24.198154),
+    (0.000035, 239.56, 25.513099),
+    (0.000023, 331.55, 3.592518),
+];
+
+/// Computes the moment (UTC) of the true new moon of lunation `k`
+/// (Meeus, chapter 49; `k` = 0 is the new moon of 6 January 2000; accurate to well under a minute)
+fn new_moon(k: i64) -> DateTime<Utc> {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let jde = 2451550.09766 + SYNODIC_MONTH * k + 0.00015437 * t.powi(2) - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4);
+    let e = 1. - 0.002516 * t - 0.0000074 * t.powi(2);
+    let radians = |degrees: f64| normalize_degrees(degrees) * PI / 180.;
+    let m = radians(2.5534 + 29.10535670 * k - 0.0000014 * t.powi(2) - 0.00000011 * t.powi(3));
+    let m_prime = radians(
+        201.5643 + 385.81693528 * k + 0.0107582 * t.powi(2) + 0.00001238 * t.powi(3)
+            - 0.000000058 * t.powi(4),
+    );
+    let f = radians(
+        160.7108 + 390.67050284 * k - 0.0016118 * t.powi(2) - 0.00000227 * t.powi(3)
+            + 0.000000011 * t.powi(4),
+    );
+    let omega = radians(124.7746 - 1.56375588 * k + 0.0020672 * t.powi(2) + 0.00000215 * t.powi(3));
+    let periodic: f64 = NEW_MOON_TERMS
+        .iter()
+        .map(|(coefficient, e_power, c_m, c_m_prime, c_f, c_omega)| {
+            coefficient
+                * e.powi(*e_power)
+                * (c_m * m + c_m_prime * m_prime + c_f * f + c_omega * omega).sin()
+        })
+        .sum();
+    let planetary: f64 = 0.000325 * radians(299.77 + 0.107408 * k - 0.009173 * t.powi(2)).sin()
+        + PLANETARY_TERMS
+            .iter()
+            .map(|(coefficient, a0, a1)| coefficient * radians(a0 + a1 * k).sin())
+            .sum::<f64>();
+    let year = (2000. + k / 12.3685).floor() as i32;
+    julian_ephemeris_day_to_utc(jde + periodic + planetary, year)
+}
+
+/// Computes the moment (UTC) of the first new moon strictly after `moment`
+pub(crate) fn next_new_moon_after(moment: DateTime<Utc>) -> DateTime<Utc> {
+    let k2000 = julian_day(moment) - 2451550.09766;
+    let mut k = (k2000 / SYNODIC_MONTH).floor() as i64 - 1;
+    loop {
+        let new_moon = new_moon(k);
+        if new_moon > moment {
+            return new_moon;
+        }
+        k += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn new_moon_matches_published_times() {
+        // Meeus, example 49.a: new moon of 1977 February 18, 3h37m42s TD
+        let expected = Utc.with_ymd_and_hms(1977, 2, 18, 3, 36, 54).unwrap();
+        let actual = next_new_moon_after(Utc.with_ymd_and_hms(1977, 2, 1, 0, 0, 0).unwrap());
+        assert!((actual - expected).num_seconds().abs() <= 5, "{actual}");
+
+        // USNO published times (rounded to the minute)
+        for (year, month, day, hour, minute) in [
+            (2024, 4, 8, 18, 21),
+            (2024, 11, 1, 12, 47),
+            (2025, 10, 21, 12, 25),
+        ] {
+            let expected = Utc
+                .with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap();
+            let actual = next_new_moon_after(expected - chrono::TimeDelta::days(10));
+            assert!(
+                (actual - expected).num_seconds().abs() <= 60,
+                "expected {expected}, got {actual}"
+            );
+        }
+    }
+}
@@ -0,0 +1,503 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Julian Day of the J2000.0 epoch (2000-01-01 12:00 TT)
+const J2000: f64 = 2451545.;
+
+/// Refraction (arcminutes) at the horizon under standard conditions (1010 hPa, 10 °C)
+const STANDARD_REFRACTION: f64 = 34.;
+
+/// Rise and set times converge to within this many days (about a millisecond)
+const CONVERGENCE: f64 = 1e-8;
+
+/// Maximum number of iterations refining a rise, set, or transit time
+const MAX_ITERATIONS: usize = 10;
+
+/// Atmospheric refraction at the horizon, which lifts the image of the sun and so delays sunset
+/// (see [`HorizonCriteria`])
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Refraction {
+    /// 34′ (the default), as used by almanacs
+    #[default]
+    Standard,
+    /// 34′ scaled for the given air pressure (hPa) and temperature (°C) (Meeus, chapter 16)
+    Atmospheric {
+        /// Air pressure in hectopascals (1010 is standard)
+        pressure: f64,
+        /// Air temperature in degrees Celsius (10 is standard)
+        temperature: f64,
+    },
+    /// The given refraction in arcminutes (0 for the geometric horizon)
+    Arcminutes(f64),
+}
+
+/// The part of the disc of the sun that touches the horizon at sunrise and sunset (see [`HorizonCriteria`])
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum SolarDisc {
+    /// The upper limb (the default): the sun has entirely risen or set
+    #[default]
+    UpperLimb,
+    /// The center of the disc
+    Center,
+    /// The lower limb: the sun begins to set or has only begun to rise
+    LowerLimb,
+}
+
+/// Criteria for the moment of sunrise and sunset; the default (standard refraction of 34′ and the upper limb,
+/// so that the center of the sun is 50′ below the horizon) matches almanacs
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HorizonCriteria {
+    /// The atmospheric refraction at the horizon
+    pub refraction: Refraction,
+    /// The part of the disc of the sun touching the horizon
+    pub disc: SolarDisc,
+}
+
+/// A daily event in the apparent motion of the sun (see [`Coordinates::solar_event`][`crate::Coordinates::solar_event`])
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum SolarEvent {
+    /// The beginning of astronomical twilight: the center of the sun rises to 18° below the horizon
+    AstronomicalDawn,
+    /// The beginning of nautical twilight: the center of the sun rises to 12° below the horizon
+    NauticalDawn,
+    /// The beginning of civil twilight: the center of the sun rises to 6° below the horizon
+    CivilDawn,
+    /// Sunrise, as defined by [`HorizonCriteria`] and seen from the elevation of the coordinates
+    Sunrise,
+    /// Sunset, as defined by [`HorizonCriteria`] and seen from the elevation of the coordinates
+    Sunset,
+    /// The end of civil twilight: the center of the sun sets to 6° below the horizon
+    CivilDusk,
+    /// The end of nautical twilight: the center of the sun sets to 12° below the horizon
+    NauticalDusk,
+    /// The end of astronomical twilight: the center of the sun sets to 18° below the horizon
+    AstronomicalDusk,
+}
+
+impl Refraction {
+    /// The refraction at the horizon in degrees
+    fn degrees(&self) -> f64 {
+        match *self {
+            Refraction::Standard => STANDARD_REFRACTION / 60.,
+            Refraction::Atmospheric {
+                pressure,
+                temperature,
+            } => STANDARD_REFRACTION / 60. * pressure / 1010. * 283. / (273. + temperature),
+            Refraction::Arcminutes(arcminutes) => arcminutes / 60.,
+        }
+    }
+}
+
+impl SolarEvent {
+    /// Whether the event occurs in the morning (while the sun rises)
+    fn is_rising(&self) -> bool {
+        matches!(
+            self,
+            SolarEvent::AstronomicalDawn
+                | SolarEvent::NauticalDawn
+                | SolarEvent::CivilDawn
+                | SolarEvent::Sunrise
+        )
+    }
+
+    /// The altitude (degrees) of the center of the sun at the event, given the semi-diameter (degrees) of the sun
+    /// and the elevation (metres) of the observer
+    fn altitude(&self, criteria: HorizonCriteria, semi_diameter: f64, elevation: f64) -> f64 {
+        match self {
+            SolarEvent::Sunrise | SolarEvent::Sunset => {
+                let disc = match criteria.disc {
+                    SolarDisc::UpperLimb => semi_diameter,
+                    SolarDisc::Center => 0.,
+                    SolarDisc::LowerLimb => -semi_diameter,
+                };
+                -criteria.refraction.degrees() - disc - horizon_dip(elevation)
+            }
+            SolarEvent::CivilDawn | SolarEvent::CivilDusk => -6.,
+            SolarEvent::NauticalDawn | SolarEvent::NauticalDusk => -12.,
+            SolarEvent::AstronomicalDawn | SolarEvent::AstronomicalDusk => -18.,
+        }
+    }
+}
+
+/// Computes the dip of the horizon (degrees) seen by an observer `elevation` metres above
+/// the surrounding terrain (about 2.076′ × √elevation, including refraction);
+/// an observer below sea level sees no dip
+fn horizon_dip(elevation: f64) -> f64 {
+    2.076 * elevation.max(0.).sqrt() / 60.
+}
+
+/// The apparent position of the sun needed for rise, set, and transit times
+struct SolarPosition {
+    /// Apparent declination in radians
+    declination: f64,
+    /// Equation of time (apparent minus mean solar time) in minutes
+    equation_of_time: f64,
+    /// Apparent semi-diameter of the disc in degrees
+    semi_diameter: f64,
+}
+
+/// Computes the apparent position of the sun at the given Julian Day (UT) with the NOAA solar calculator's
+/// algorithm (Meeus, chapters 22, 25, and 28; accurate to about 0.01°)
+fn solar_position(julian_day: f64) -> SolarPosition {
+    let year = 2000 + ((julian_day - J2000) / 365.25).floor() as i32;
+    let t = (julian_day + delta_t(year) / SECONDS_IN_A_DAY - J2000) / 36525.;
+    let mean_longitude = normalize_degrees(280.46646 + t * (36000.76983 + 0.0003032 * t));
+    let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let m = mean_anomaly.to_radians();
+    let equation_of_center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2. * m).sin() * (0.019993 - 0.000101 * t)
+        + (3. * m).sin() * 0.000289;
+    let true_anomaly = (mean_anomaly + equation_of_center).to_radians();
+    let distance =
+        1.000001018 * (1. - eccentricity.powi(2)) / (1. + eccentricity * true_anomaly.cos());
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude =
+        (mean_longitude + equation_of_center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+    let mean_obliquity =
+        23. + (26. + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.) / 60.;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+    let y = (obliquity / 2.).tan().powi(2);
+    let l0 = mean_longitude.to_radians();
+    let equation_of_time = 4.
+        * (y * (2. * l0).sin() - 2. * eccentricity * m.sin()
+            + 4. * eccentricity * y * m.sin() * (2. * l0).cos()
+            - 0.5 * y * y * (4. * l0).sin()
+            - 1.25 * eccentricity.powi(2) * (2. * m).sin())
+        .to_degrees();
+    SolarPosition {
+        declination,
+        equation_of_time,
+        semi_diameter: 0.266563 / distance,
+    }
+}
+
+/// Julian Day (UT) of 0h UT on the given date
+fn julian_day_at_midnight(date: NaiveDate) -> f64 {
+    julian_day(date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+/// Julian Day (UT) of the transit of the sun across the meridian at `longitude` (solar noon) on the given date
+fn transit(longitude: f64, date: NaiveDate) -> f64 {
+    let midnight = julian_day_at_midnight(date);
+    let mut moment = midnight + 0.5 - longitude / 360.;
+    for _ in 0..MAX_ITERATIONS {
+        let next =
+            midnight + (720. - 4. * longitude - solar_position(moment).equation_of_time) / 1440.;
+        if (next - moment).abs() < CONVERGENCE {
+            return next;
+        }
+        moment = next;
+    }
+    moment
+}
+
+/// Computes the moment (UTC) of solar noon (the transit of the sun) at `longitude` on the given Gregorian date
+pub(crate) fn solar_noon(longitude: f64, date: NaiveDate) -> DateTime<Utc> {
+    julian_day_to_utc(transit(longitude, date))
+}
+
+/// Computes the moment (UTC) of the given [`SolarEvent`] in the solar day around noon of the given Gregorian date
+/// at the given latitude, longitude, and elevation (metres); returns `None` if the sun does not reach the altitude
+/// of the event on that date (e.g. no sunset in the midnight sun)
+pub(crate) fn solar_event(
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+    event: SolarEvent,
+    criteria: HorizonCriteria,
+    date: NaiveDate,
+) -> Option<DateTime<Utc>> {
+    let midnight = julian_day_at_midnight(date);
+    let latitude = latitude.to_radians();
+    let direction = if event.is_rising() { -1. } else { 1. };
+    // refine the hour angle with the position of the sun at the moment of the event
+    let mut moment = transit(longitude, date);
+    for _ in 0..MAX_ITERATIONS {
+        let position = solar_position(moment);
+        let altitude = event
+            .altitude(criteria, position.semi_diameter, elevation)
+            .to_radians();
+        let cos_hour_angle = (altitude.sin() - latitude.sin() * position.declination.sin())
+            / (latitude.cos() * position.declination.cos());
+        if !(-1. ..=1.).contains(&cos_hour_angle) {
+            return None;
+        }
+        let hour_angle = cos_hour_angle.acos().to_degrees();
+        let next = midnight
+            + (720. - 4. * (longitude - direction * hour_angle) - position.equation_of_time)
+                / 1440.;
+        if (next - moment).abs() < CONVERGENCE {
+            moment = next;
+            break;
+        }
+        moment = next;
+    }
+    Some(julian_day_to_utc(moment))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, TimeZone};
+    use chrono_tz::Tz;
+
+    use super::*;
+
+    fn date(ymd: (i32, u32, u32)) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).unwrap()
+    }
+
+    fn sunset_at(
+        latitude: f64,
+        longitude: f64,
+        elevation: f64,
+        ymd: (i32, u32, u32),
+    ) -> DateTime<Utc> {
+        solar_event(
+            latitude,
+            longitude,
+            elevation,
+            SolarEvent::Sunset,
+            HorizonCriteria::default(),
+            date(ymd),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn solar_events_match_almanac() {
+        // published local times (rounded to the minute) of solar noon, sunrise, sunset, and the end of civil twilight
+        for (timezone, latitude, longitude, ymd, events) in [
+            // Greenwich, June solstice
+            (
+                "Europe/London",
+                51.4769,
+                -0.0005,
+                (2024, 6, 21),
+                [
+                    (SolarEvent::Sunrise, "04:43"),
+                    (SolarEvent::Sunset, "21:21"),
+                    (SolarEvent::CivilDusk, "22:09"),
+                ],
+            ),
+            // New York, December solstice
+            (
+                "America/New_York",
+                40.7128,
+                -74.006,
+                (2024, 12, 21),
+                [
+                    (SolarEvent::Sunrise, "07:17"),
+                    (SolarEvent::Sunset, "16:32"),
+                    (SolarEvent::CivilDusk, "17:03"),
+                ],
+            ),
+            // Sydney, December solstice
+            (
+                "Australia/Sydney",
+                -33.8688,
+                151.2093,
+                (2024, 12, 21),
+                [
+                    (SolarEvent::Sunrise, "05:41"),
+                    (SolarEvent::Sunset, "20:05"),
+                    (SolarEvent::CivilDusk, "20:34"),
+                ],
+            ),
+        ] {
+            let timezone: Tz = timezone.parse().unwrap();
+            for (event, expected) in events {
+                let expected = timezone
+                    .from_local_datetime(
+                        &NaiveDateTime::parse_from_str(
+                            &format!("{}-{}-{} {expected}", ymd.0, ymd.1, ymd.2),
+                            "%Y-%m-%d %H:%M",
+                        )
+                        .unwrap(),
+                    )
+                    .unwrap();
+                let actual = solar_event(
+                    latitude,
+                    longitude,
+                    0.,
+                    event,
+                    HorizonCriteria::default(),
+                    date(ymd),
+                )
+                .unwrap();
+                assert!(
+                    (actual - expected.to_utc()).num_seconds().abs() <= 60,
+                    "{event:?} {ymd:?}: expected {expected}, got {}",
+                    actual.with_timezone(&timezone)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solar_noon_matches_almanac() {
+        // Greenwich, 2024-06-21 13:01:55 BST and 2024-11-03 11:43:36 GMT (the equation of time is near its maximum)
+        let june = solar_noon(-0.0005, date((2024, 6, 21)));
+        let expected = Utc.with_ymd_and_hms(2024, 6, 21, 12, 1, 55).unwrap();
+        assert!((june - expected).num_seconds().abs() <= 5, "{june}");
+        let november = solar_noon(-0.0005, date((2024, 11, 3)));
+        let expected = Utc.with_ymd_and_hms(2024, 11, 3, 11, 43, 36).unwrap();
+        assert!((november - expected).num_seconds().abs() <= 5, "{november}");
+    }
+
+    #[test]
+    fn sub_second_precision() {
+        let sunset = sunset_at(35.696111, 51.423056, 0., (2026, 3, 20));
+        let later = solar_event(
+            35.696111,
+            51.423056,
+            0.,
+            SolarEvent::Sunset,
+            HorizonCriteria {
+                refraction: Refraction::Arcminutes(STANDARD_REFRACTION + 0.01),
+                ..Default::default()
+            },
+            date((2026, 3, 20)),
+        )
+        .unwrap();
+        // 0.01′ of refraction is less than a tenth of a second at this latitude
+        let difference = (later - sunset).num_milliseconds();
+        assert!((1..100).contains(&difference), "{difference}");
+    }
+
+    #[test]
+    fn horizon_criteria() {
+        let ymd = (2024, 3, 19);
+        let at = |criteria| {
+            solar_event(
+                39.613319,
+                -105.016647,
+                0.,
+                SolarEvent::Sunset,
+                criteria,
+                date(ymd),
+            )
+            .unwrap()
+        };
+        let standard = at(HorizonCriteria::default());
+        let center = at(HorizonCriteria {
+            disc: SolarDisc::Center,
+            ..Default::default()
+        });
+        let lower_limb = at(HorizonCriteria {
+            disc: SolarDisc::LowerLimb,
+            ..Default::default()
+        });
+        let geometric = at(HorizonCriteria {
+            refraction: Refraction::Arcminutes(0.),
+            disc: SolarDisc::Center,
+        });
+        // the disc is about 32′ across and sets at about 4 minutes per degree / cos(latitude) near the equinox
+        assert!((60..=100).contains(&(standard - center).num_seconds()));
+        assert!((60..=100).contains(&(center - lower_limb).num_seconds()));
+        assert!((160..=190).contains(&(center - geometric).num_seconds()));
+        // cold, dense air refracts more
+        let cold = at(HorizonCriteria {
+            refraction: Refraction::Atmospheric {
+                pressure: 1030.,
+                temperature: -20.,
+            },
+            ..Default::default()
+        });
+        assert!(cold > standard);
+        let standard_atmosphere = at(HorizonCriteria {
+            refraction: Refraction::Atmospheric {
+                pressure: 1010.,
+                temperature: 10.,
+            },
+            ..Default::default()
+        });
+        assert!((standard_atmosphere - standard).num_milliseconds().abs() <= 1);
+    }
+
+    #[test]
+    fn elevation_delays_sunset() {
+        for (latitude, longitude, elevation, ymd, minutes) in [
+            // Denver (1609 m)
+            (39.739236, -104.984862, 1609., (2024, 3, 19), 6..=8),
+            // La Paz (3640 m)
+            (-16.489689, -68.119294, 3640., (2024, 6, 21), 8..=10),
+            // Addis Ababa (2355 m)
+            (9.005401, 38.763611, 2355., (2024, 12, 21), 6..=8),
+        ] {
+            let sea_level = sunset_at(latitude, longitude, 0., ymd);
+            let elevated = sunset_at(latitude, longitude, elevation, ymd);
+            let delay = (elevated - sea_level).num_minutes();
+            assert!(minutes.contains(&delay), "{latitude}, {longitude}: {delay}");
+        }
+        // below sea level (the Dead Sea) the horizon does not dip
+        assert_eq!(
+            sunset_at(31.5, 35.5, -430., (2024, 3, 19)),
+            sunset_at(31.5, 35.5, 0., (2024, 3, 19))
+        );
+    }
+
+    #[test]
+    fn events_in_order() {
+        let events = [
+            SolarEvent::AstronomicalDawn,
+            SolarEvent::NauticalDawn,
+            SolarEvent::CivilDawn,
+            SolarEvent::Sunrise,
+            SolarEvent::Sunset,
+            SolarEvent::CivilDusk,
+            SolarEvent::NauticalDusk,
+            SolarEvent::AstronomicalDusk,
+        ];
+        let moments: Vec<_> = events
+            .iter()
+            .map(|event| {
+                solar_event(
+                    39.613319,
+                    -105.016647,
+                    0.,
+                    *event,
+                    HorizonCriteria::default(),
+                    date((2024, 3, 19)),
+                )
+                .unwrap()
+            })
+            .collect();
+        assert!(moments.windows(2).all(|pair| pair[0] < pair[1]));
+        let noon = solar_noon(-105.016647, date((2024, 3, 19)));
+        assert!(moments[3] < noon && noon < moments[4]);
+        // sunrise and sunset are almost symmetric about solar noon (the declination changes fastest near the equinox)
+        assert!(
+            ((noon - moments[3]) - (moments[4] - noon))
+                .num_seconds()
+                .abs()
+                < 90
+        );
+    }
+
+    #[test]
+    fn polar_day_and_night() {
+        let solstice = date((2024, 6, 21));
+        let at = |latitude, longitude, event| {
+            solar_event(
+                latitude,
+                longitude,
+                0.,
+                event,
+                HorizonCriteria::default(),
+                solstice,
+            )
+        };
+        // the midnight sun in Longyearbyen and the polar night at McMurdo Station
+        assert_eq!(at(78.22, 15.65, SolarEvent::Sunset), None);
+        assert_eq!(at(78.22, 15.65, SolarEvent::Sunrise), None);
+        assert_eq!(at(-77.85, 166.67, SolarEvent::Sunset), None);
+        assert!(at(-77.85, 166.67, SolarEvent::AstronomicalDusk).is_some());
+        // nautical twilight lasts all night in Edinburgh
+        assert!(at(55.95, -3.19, SolarEvent::CivilDusk).is_some());
+        assert_eq!(at(55.95, -3.19, SolarEvent::NauticalDusk), None);
+    }
+}
@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use chrono_tz::Asia::Tehran;
+use lazy_static::lazy_static;
+
+use super::*;
+
+/// Latitude of Ṭihrán, the point of reference for Naw-Rúz and the Twin Holy Days
+pub(crate) const TEHRAN_LATITUDE: f64 = 35.696111;
+/// Longitude of Ṭihrán, the point of reference for Naw-Rúz and the Twin Holy Days
+pub(crate) const TEHRAN_LONGITUDE: f64 = 51.423056;
+
+lazy_static! {
+    /// Naw-Rúz dates already computed, by Gregorian year
+    static ref NAW_RUZ_DATES: Mutex<HashMap<i32, NaiveDate>> = Mutex::new(HashMap::new());
+    /// Birth of the Báb dates already computed, by Gregorian year
+    static ref BIRTH_OF_THE_BAB_DATES: Mutex<HashMap<i32, NaiveDate>> = Mutex::new(HashMap::new());
+}
+
+/// Looks up the date memoized for `year` in `cache`, computing and memoizing it if absent
+/// (each year's dates are needed for every Badí‘ date of that year but cost a few equinox or new moon computations)
+fn memoized(
+    cache: &Mutex<HashMap<i32, NaiveDate>>,
+    year: i32,
+    compute: fn(i32) -> NaiveDate,
+) -> NaiveDate {
+    if let Some(date) = cache.lock().unwrap().get(&year) {
+        return *date;
+    }
+    let date = compute(year);
+    cache.lock().unwrap().insert(year, date);
+    date
+}
+
+/// Computes the moment of sunset in Ṭihrán on the given Gregorian date
+pub(crate) fn tehran_sunset(date: NaiveDate) -> DateTime<Utc> {
+    solar_event(
+        TEHRAN_LATITUDE,
+        TEHRAN_LONGITUDE,
+        0.,
+        SolarEvent::Sunset,
+        HorizonCriteria::default(),
+        date,
+    )
+    .expect("the sun sets every day in Ṭihrán")
+}
+
+/// Computes the Gregorian date of the Badí‘ day (sunset to sunset in Ṭihrán) in which `moment` occurs
+/// (the Gregorian date on which most of that Badí‘ day falls)
+pub(crate) fn tehran_badi_day(moment: DateTime<Utc>) -> NaiveDate {
+    let date = moment.with_timezone(&Tehran).date_naive();
+    if moment < tehran_sunset(date) {
+        date
+    } else {
+        date + Days::new(1)
+    }
+}
+
+/// Computes the Gregorian date of Naw-Rúz in the given Gregorian year:
+/// the Badí‘ day in Ṭihrán during which the sun enters Aries (the March equinox)
+pub(crate) fn naw_ruz_date(year: i32) -> NaiveDate {
+    memoized(&NAW_RUZ_DATES, year, |year| {
+        tehran_badi_day(march_equinox(year))
+    })
+}
+
+/// Computes the Gregorian date of the Birth of the Báb in the given Gregorian year:
+/// the Badí‘ day in Ṭihrán following the eighth new moon after (the day of) Naw-Rúz
+pub(crate) fn birth_of_the_bab_date(year: i32) -> NaiveDate {
+    memoized(&BIRTH_OF_THE_BAB_DATES, year, |year| {
+        let mut new_moon = tehran_sunset(naw_ruz_date(year));
+        for _ in 0..8 {
+            new_moon = next_new_moon_after(new_moon);
+        }
+        tehran_badi_day(new_moon) + Days::new(1)
+    })
+}
@@ -1,9 +1,38 @@
-use crate::{BadiDateError, BadiMonth};
+use chrono::Days;
+
+use super::format::FORMAT_DEFAULT_LOCALE;
+use crate::{BadiDateError, BadiDelayedFormat, BadiMonth, BadiWeekday, CalendarRules, VahidYear};
 
 /// Common trait for [`BadiDate`][`crate::BadiDate`] and [`LocalBadiDate`][`crate::LocalBadiDate`]
 pub trait BadiDateLike: Clone {
-    /// The Bahá’í Era/Badi year [1 - 221 supported] (year 1 starts 21 March 1844)
-    fn year(&self) -> u8;
+    /// The Bahá’í Era/Badi year [1 - 1000 supported] (year 1 began at sunset on 20 March 1844; under the default
+    /// [`CalendarRules::Unified2015`], applied to years before 172 B.E. too, 1 Bahá 1 B.E. is 20 March 1844)
+    fn year(&self) -> u16;
+
+    /// The year within the current Váḥid [1 - 19]
+    fn year_in_vahid(&self) -> u16 {
+        self.vahid_year().year_in_vahid()
+    }
+
+    /// The Váḥid (19-year cycle) within the current Kull-i-Shay [1 - 19]
+    fn vahid(&self) -> u16 {
+        self.vahid_year().vahid()
+    }
+
+    /// The Kull-i-Shay (361-year cycle; the first started in 1844)
+    fn kull_i_shay(&self) -> u16 {
+        self.vahid_year().kull_i_shay()
+    }
+
+    /// The year as a [`VahidYear`] (displays as e.g. "Year Bahá of the 10th Váḥid of the 1st Kull-i-Shay")
+    fn vahid_year(&self) -> VahidYear {
+        VahidYear::new(self.year()).expect("the year of a Badi date is supported")
+    }
+
+    /// Name of the year within the current Váḥid in a locale (see [`VahidYear::name`])
+    fn year_name(&self, locale: &str) -> String {
+        self.vahid_year().name(locale)
+    }
 
     /// The Badi month [1 - 19] or Ayyám-i-Há
     fn month(&self) -> BadiMonth;
@@ -11,6 +40,18 @@ pub trait BadiDateLike: Clone {
     /// The Badi day [1 - min(19, Ayyám-i-Há days for the year)]
     fn day(&self) -> u16;
 
+    /// Name of the day of the month in a locale (see [`BadiMonth::name`]): each of the 19 days
+    /// has the name of the corresponding month (day 1 = Bahá, day 2 = Jalál, ...)
+    /// Returns `None` during Ayyám-i-Há, whose days are only numbered
+    fn day_name(&self, locale: &str) -> Option<String> {
+        match self.month() {
+            BadiMonth::AyyamIHa => None,
+            _ => BadiMonth::try_from(self.day() as u8)
+                .ok()
+                .map(|day| day.name(locale)),
+        }
+    }
+
     /// Whether the current date is a Feast day
     fn is_feast(&self) -> bool {
         self.day() == 1 && self.month() != BadiMonth::AyyamIHa
@@ -19,8 +60,70 @@ pub trait BadiDateLike: Clone {
     /// The day of the current year (starting with 1 on Naw-Rúz)
     fn day_of_year(&self) -> u16;
 
+    /// The [`BadiWeekday`] (the weekday of the Gregorian date on which the Badi day ends,
+    /// as the Badi day starts at sunset on the previous Gregorian day)
+    fn weekday(&self) -> BadiWeekday {
+        self.calendar_rules()
+            .weekday(self.year(), self.day_of_year())
+    }
+
+    /// Formats the date with a pattern of Badi-specific specifiers (see [`BadiDelayedFormat`]),
+    /// using English transliterations for names
+    fn format<'a>(&self, pattern: &'a str) -> BadiDelayedFormat<'a> {
+        self.format_localized(pattern, FORMAT_DEFAULT_LOCALE)
+    }
+
+    /// Formats the date with a pattern of Badi-specific specifiers (see [`BadiDelayedFormat`]),
+    /// using names in the given `locale` (see /locales/app.yaml)
+    fn format_localized<'a>(&self, pattern: &'a str, locale: &'a str) -> BadiDelayedFormat<'a> {
+        BadiDelayedFormat::new(
+            self.year(),
+            self.month(),
+            self.day(),
+            self.day_of_year(),
+            self.calendar_rules(),
+            pattern,
+            locale,
+        )
+    }
+
+    /// The [`CalendarRules`] used to determine Naw-Rúz, Ayyám-i-Há, and holy days
+    fn calendar_rules(&self) -> CalendarRules;
+
+    /// Number of days since 1 Bahá 1 B.E. (day 0) under the date's [`CalendarRules`] (20 March 1844 under
+    /// [`CalendarRules::Unified2015`], 21 March 1844 under [`CalendarRules::WesternGregorianPinned`])
+    fn to_epoch_days(&self) -> i32 {
+        let rules = self.calendar_rules();
+        (rules.naw_ruz_date(self.year()) - rules.naw_ruz_date(1)).num_days() as i32
+            + self.day_of_year() as i32
+            - 1
+    }
+
+    /// Returns new [`BadiDateLike`] that is the given number of `days` since 1 Bahá 1 B.E. (day 0)
+    /// under the date's [`CalendarRules`] (checks input for validity)
+    fn with_epoch_days(&self, days: i32) -> Result<Self, BadiDateError> {
+        let rules = self.calendar_rules();
+        let date = u64::try_from(days)
+            .ok()
+            .and_then(|days| rules.naw_ruz_date(1).checked_add_days(Days::new(days)))
+            .ok_or(BadiDateError::DateNotSupported)?;
+        let (year, day_of_year) = rules.year_and_day_of_year(date)?;
+        self.with_year_and_doy(year, day_of_year)
+    }
+
+    /// Number of days from `self` until `other` (negative if `other` is before `self`)
+    fn days_until<O: BadiDateLike>(&self, other: &O) -> i32 {
+        let date = self
+            .calendar_rules()
+            .gregorian_date(self.year(), self.day_of_year());
+        let other_date = other
+            .calendar_rules()
+            .gregorian_date(other.year(), other.day_of_year());
+        (other_date - date).num_days() as i32
+    }
+
     /// Returns new [`BadiDateLike`] with the given `year` (checks input for validity)
-    fn with_year(&self, year: u8) -> Result<Self, BadiDateError>;
+    fn with_year(&self, year: u16) -> Result<Self, BadiDateError>;
 
     /// Returns new [`BadiDateLike`] with the given `month` (checks input for validity)
     fn with_month(&self, month: BadiMonth) -> Result<Self, BadiDateError>;
@@ -29,25 +132,74 @@ pub trait BadiDateLike: Clone {
     fn with_day(&self, day: u16) -> Result<Self, BadiDateError>;
 
     /// Returns new [`BadiDateLike`] with the given `year`, `month`, and `day` (checks input for validity)
-    fn with_ymd(&self, year: u8, month: BadiMonth, day: u16) -> Result<Self, BadiDateError>;
+    fn with_ymd(&self, year: u16, month: BadiMonth, day: u16) -> Result<Self, BadiDateError>;
 
     /// Returns new [`BadiDateLike`] with the given `year` and **1-based** `day_of_year` (checks input for validity)
-    fn with_year_and_doy(&self, year: u8, day_of_year: u16) -> Result<Self, BadiDateError>;
+    fn with_year_and_doy(&self, year: u16, day_of_year: u16) -> Result<Self, BadiDateError>;
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{BadiDate, BadiDateLike, BadiMonth};
+    use crate::{BadiDate, BadiDateLike, BadiMonth, CalendarRules};
 
     #[test]
     fn test_is_feast() {
-        let badi = BadiDate::new(181, BadiMonth::Month(19), 2).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Ala, 2).unwrap();
         assert!(!badi.is_feast());
 
-        let badi = BadiDate::new(181, BadiMonth::Month(19), 1).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Ala, 1).unwrap();
         assert!(badi.is_feast());
 
         let badi = BadiDate::new(181, BadiMonth::AyyamIHa, 1).unwrap();
         assert!(!badi.is_feast());
     }
+
+    #[test]
+    fn epoch_days() {
+        let first = BadiDate::new(1, BadiMonth::Baha, 1).unwrap();
+        assert_eq!(first.to_epoch_days(), 0);
+        assert_eq!(first.with_epoch_days(0).unwrap(), first);
+        assert_eq!(
+            first.with_epoch_days(365).unwrap(),
+            BadiDate::new(1, BadiMonth::Ala, 19).unwrap()
+        );
+        assert!(first.with_epoch_days(-1).is_err());
+        assert_eq!(BadiDate::from_epoch_days(365).unwrap().to_epoch_days(), 365);
+
+        let badi = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        // 20 March 1844 (proleptic Naw-Rúz 1 B.E.) to 20 March 2024
+        assert_eq!(badi.to_epoch_days(), 65744);
+        assert_eq!(badi.with_epoch_days(65744).unwrap(), badi);
+        assert_eq!(
+            badi.with_epoch_days(65743).unwrap(),
+            BadiDate::new(180, BadiMonth::Ala, 19).unwrap()
+        );
+
+        let last = BadiDate::new(1000, BadiMonth::Ala, 19).unwrap();
+        assert_eq!(last.with_epoch_days(last.to_epoch_days()).unwrap(), last);
+        assert!(last.with_epoch_days(last.to_epoch_days() + 1).is_err());
+        assert!(last.with_epoch_days(i32::MAX).is_err());
+
+        let rules = CalendarRules::WesternGregorianPinned;
+        let western = BadiDate::new_with_rules(181, BadiMonth::Baha, 1, rules).unwrap();
+        // 21 March 1844 to 21 March 2024
+        assert_eq!(western.to_epoch_days(), 65744);
+        assert_eq!(western.with_epoch_days(65744).unwrap(), western);
+    }
+
+    #[test]
+    fn day_name() {
+        let badi = BadiDate::new(181, BadiMonth::Nur, 7).unwrap();
+        assert_eq!(badi.day_name("tl").unwrap(), "Kalimát");
+        assert_eq!(badi.day_name("en").unwrap(), "Words");
+        assert_eq!(badi.month().name("tl"), "Núr");
+
+        let badi = BadiDate::new(181, BadiMonth::Ala, 1).unwrap();
+        assert_eq!(badi.day_name("tl").unwrap(), "Bahá");
+        let badi = BadiDate::new(181, BadiMonth::Baha, 19).unwrap();
+        assert_eq!(badi.day_name("tl").unwrap(), "ʻAláʼ");
+
+        let badi = BadiDate::new(181, BadiMonth::AyyamIHa, 2).unwrap();
+        assert_eq!(badi.day_name("tl"), None);
+    }
 }
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{BadiDateLike, BadiMonth};
+use super::badi_duration::duration_between;
+use crate::{statics::*, BadiDateError, BadiDateLike, BadiDuration, BadiMonth};
 
-/// Determines resulting day in a returned [`BadiDateLike`][`crate::BadiDateLike`] copy when adding/subtracting [`BadiMonth`]s
+/// Determines resulting day in a returned [`BadiDateLike`][`crate::BadiDateLike`] copy when adding/subtracting [`BadiMonth`]s or years
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub enum DayChangeAction {
     /// Take the minimum of `self.day` and number of days in **resulting** [`BadiMonth`]
@@ -15,24 +16,39 @@ pub enum DayChangeAction {
 }
 
 /// Provides methods to return a modified copy of a [`BadiDateLike`]
+///
+/// Methods without the `checked_` prefix panic if the result is not in the supported range;
+/// their `checked_` variants return [`BadiDateError::DateNotSupported`] instead
 pub trait BadiDateOps<T>
 where
     T: BadiDateLike,
 {
     /// Returns new [`BadiDateLike`] of the next Feast (day 1 of next [`BadiMonth`]; **skips** [`BadiMonth::AyyamIHa`])
     fn next_feast(&self) -> T;
-    /// Returns new [`BadiDateLike`] of the previous Feast (day 1 of [`BadiMonth::Month`] -- `self.month` is kept if `self.day` > 1), **skips** [`BadiMonth::AyyamIHa`])
+    /// Returns new [`BadiDateLike`] of the next Feast (see [`BadiDateOps::next_feast`])
+    fn checked_next_feast(&self) -> Result<T, BadiDateError>;
+    /// Returns new [`BadiDateLike`] of the previous Feast (day 1 of a [`BadiMonth`] -- `self.month` is kept if `self.day` > 1), **skips** [`BadiMonth::AyyamIHa`])
     /// If `self.day` == 1, returns `self`.
     fn previous_or_current_feast(&self) -> T;
-    /// Returns new [`BadiDateLike`] of the previous Feast (day 1 of [`BadiMonth::Month`] -- `self.month` is kept if `self.day` > 1), **skips** [`BadiMonth::AyyamIHa`])
+    /// Returns new [`BadiDateLike`] of the previous or current Feast (see [`BadiDateOps::previous_or_current_feast`])
+    fn checked_previous_or_current_feast(&self) -> Result<T, BadiDateError>;
+    /// Returns new [`BadiDateLike`] of the previous Feast (day 1 of a [`BadiMonth`] -- `self.month` is kept if `self.day` > 1), **skips** [`BadiMonth::AyyamIHa`])
     /// If `self.day` == 1, returns the previous month's Feast.
     fn previous_feast(&self) -> T;
-    /// Returns new [`BadiDateLike`] of the next Naw Ruz (day 1 of `BadiMonth::Month(1)` of next `year`)
+    /// Returns new [`BadiDateLike`] of the previous Feast (see [`BadiDateOps::previous_feast`])
+    fn checked_previous_feast(&self) -> Result<T, BadiDateError>;
+    /// Returns new [`BadiDateLike`] of the next Naw Ruz (day 1 of `BadiMonth::Baha` of next `year`)
     fn next_naw_ruz(&self) -> T;
-    /// Returns new [`BadiDateLike`] of the previous Naw Ruz (day 1 of `BadiMonth::Month(1)` -- `self.year` is kept if `self` > current Naw Ruz)
+    /// Returns new [`BadiDateLike`] of the next Naw Ruz (see [`BadiDateOps::next_naw_ruz`])
+    fn checked_next_naw_ruz(&self) -> Result<T, BadiDateError>;
+    /// Returns new [`BadiDateLike`] of the previous Naw Ruz (day 1 of `BadiMonth::Baha` -- `self.year` is kept if `self` > current Naw Ruz)
     fn previous_naw_ruz(&self) -> T;
+    /// Returns new [`BadiDateLike`] of the previous Naw Ruz (see [`BadiDateOps::previous_naw_ruz`])
+    fn checked_previous_naw_ruz(&self) -> Result<T, BadiDateError>;
     /// Returns new [`BadiDateLike`] with number of days added (subtracted if negative) (increments `month` and `year` accordingly; **includes** [`BadiMonth::AyyamIHa`])
     fn add_days(&self, days: i32) -> T;
+    /// Returns new [`BadiDateLike`] with number of days added (subtracted if negative) (see [`BadiDateOps::add_days`])
+    fn checked_add_days(&self, days: i32) -> Result<T, BadiDateError>;
     /// Add (subtract if months negative) number of `months` to [`BadiDateLike`]
     /// * `day_change_action` - determines the resulting day (see [`DayChangeAction`])
     /// * `skip_ayyamiha` - whether to skip over the "month" of Ayyám-i-Há
@@ -42,14 +58,42 @@ where
         day_change_action: DayChangeAction,
         skip_ayyamiha: bool,
     ) -> Self;
+    /// Add (subtract if months negative) number of `months` to [`BadiDateLike`] (see [`BadiDateOps::add_months`])
+    fn checked_add_months(
+        &self,
+        months: i32,
+        day_change_action: DayChangeAction,
+        skip_ayyamiha: bool,
+    ) -> Result<T, BadiDateError>;
+    /// Add (subtract if years negative) number of `years` to [`BadiDateLike`], keeping the month
+    /// * `day_change_action` - determines the resulting day (see [`DayChangeAction`]; with
+    ///   [`DayChangeAction::Keep`], 5 Ayyám-i-Há becomes 4 Ayyám-i-Há in a year with 4 days of Ayyám-i-Há)
+    fn add_years(&self, years: i32, day_change_action: DayChangeAction) -> T;
+    /// Add (subtract if years negative) number of `years` to [`BadiDateLike`] (see [`BadiDateOps::add_years`])
+    fn checked_add_years(
+        &self,
+        years: i32,
+        day_change_action: DayChangeAction,
+    ) -> Result<T, BadiDateError>;
     /// Convenience method to goto previous Badi month
     fn previous_month(&self, skip_ayyamiha: bool) -> T;
+    /// Convenience method to goto previous Badi month (see [`BadiDateOps::checked_add_months`])
+    fn checked_previous_month(&self, skip_ayyamiha: bool) -> Result<T, BadiDateError>;
     /// Convenience method to goto next Badi month
     fn next_month(&self, skip_ayyamiha: bool) -> T;
+    /// Convenience method to goto next Badi month (see [`BadiDateOps::checked_add_months`])
+    fn checked_next_month(&self, skip_ayyamiha: bool) -> Result<T, BadiDateError>;
     /// Convenience method to goto previous Badi day
     fn previous_day(&self) -> T;
+    /// Convenience method to goto previous Badi day (see [`BadiDateOps::checked_add_days`])
+    fn checked_previous_day(&self) -> Result<T, BadiDateError>;
     /// Convenience method to goto next Badi day
     fn next_day(&self) -> T;
+    /// Convenience method to goto next Badi day (see [`BadiDateOps::checked_add_days`])
+    fn checked_next_day(&self) -> Result<T, BadiDateError>;
+    /// Calendar-aware difference from `self` until `other` in years, months, and days (see [`BadiDuration`])
+    /// * `skip_ayyamiha` - whether to skip over the "month" of Ayyám-i-Há when counting months (as in [`BadiDateOps::add_months`])
+    fn duration_until(&self, other: &T, skip_ayyamiha: bool) -> BadiDuration;
 }
 
 impl<T> BadiDateOps<T> for T
@@ -57,39 +101,67 @@ where
     T: BadiDateLike,
 {
     fn next_feast(&self) -> T {
-        self.add_months(1, DayChangeAction::FirstInMonth, true)
+        self.checked_next_feast().unwrap()
+    }
+
+    fn checked_next_feast(&self) -> Result<T, BadiDateError> {
+        self.checked_add_months(1, DayChangeAction::FirstInMonth, true)
     }
 
     fn previous_feast(&self) -> T {
+        self.checked_previous_feast().unwrap()
+    }
+
+    fn checked_previous_feast(&self) -> Result<T, BadiDateError> {
         if self.is_feast() || self.month() == BadiMonth::AyyamIHa {
-            self.add_months(-1, DayChangeAction::FirstInMonth, true)
+            self.checked_add_months(-1, DayChangeAction::FirstInMonth, true)
         } else {
-            self.with_day(1).unwrap()
+            self.with_day(1)
         }
     }
 
     fn previous_or_current_feast(&self) -> T {
+        self.checked_previous_or_current_feast().unwrap()
+    }
+
+    fn checked_previous_or_current_feast(&self) -> Result<T, BadiDateError> {
         if self.is_feast() {
-            self.clone()
+            Ok(self.clone())
         } else {
-            self.previous_feast()
+            self.checked_previous_feast()
         }
     }
 
     fn next_day(&self) -> Self {
-        self.add_days(1)
+        self.checked_next_day().unwrap()
+    }
+
+    fn checked_next_day(&self) -> Result<Self, BadiDateError> {
+        self.checked_add_days(1)
     }
 
     fn previous_day(&self) -> Self {
-        self.add_days(-1)
+        self.checked_previous_day().unwrap()
+    }
+
+    fn checked_previous_day(&self) -> Result<Self, BadiDateError> {
+        self.checked_add_days(-1)
     }
 
     fn next_month(&self, skip_ayyamiha: bool) -> Self {
-        self.add_months(1, DayChangeAction::Keep, skip_ayyamiha)
+        self.checked_next_month(skip_ayyamiha).unwrap()
+    }
+
+    fn checked_next_month(&self, skip_ayyamiha: bool) -> Result<Self, BadiDateError> {
+        self.checked_add_months(1, DayChangeAction::Keep, skip_ayyamiha)
     }
 
     fn previous_month(&self, skip_ayyamiha: bool) -> Self {
-        self.add_months(-1, DayChangeAction::Keep, skip_ayyamiha)
+        self.checked_previous_month(skip_ayyamiha).unwrap()
+    }
+
+    fn checked_previous_month(&self, skip_ayyamiha: bool) -> Result<Self, BadiDateError> {
+        self.checked_add_months(-1, DayChangeAction::Keep, skip_ayyamiha)
     }
 
     fn add_months(
@@ -98,141 +170,211 @@ where
         day_change_action: DayChangeAction,
         skip_ayyamiha: bool,
     ) -> Self {
-        if months == 0 {
-            return self.clone();
-        }
-        let abs_months = months.abs();
-        let positive = months > 0;
-        let mut day = self.day();
-        let mut month = self.month();
-        let mut year = self.year();
-        for _ in 0..abs_months {
-            if positive {
-                if let Some(m) = month.next() {
-                    if skip_ayyamiha && m == BadiMonth::AyyamIHa {
-                        month = m.next().unwrap();
-                    } else {
-                        month = m;
-                    }
-                } else {
-                    month = BadiMonth::first();
-                    year += 1;
-                }
-            } else if let Some(m) = month.previous() {
-                if skip_ayyamiha && m == BadiMonth::AyyamIHa {
-                    month = m.previous().unwrap();
-                } else {
-                    month = m;
-                }
-            } else {
-                month = BadiMonth::last();
-                year -= 1;
-            }
-            match day_change_action {
-                DayChangeAction::Keep => day = day.min(month.number_of_days(year)),
-                DayChangeAction::FirstInMonth => day = 1,
-                DayChangeAction::LastInMonth => day = month.number_of_days(year),
-            };
+        self.checked_add_months(months, day_change_action, skip_ayyamiha)
+            .unwrap()
+    }
+
+    fn checked_add_months(
+        &self,
+        months: i32,
+        day_change_action: DayChangeAction,
+        skip_ayyamiha: bool,
+    ) -> Result<Self, BadiDateError> {
+        add_months(self, months, day_change_action, skip_ayyamiha)
+    }
+
+    fn add_years(&self, years: i32, day_change_action: DayChangeAction) -> Self {
+        self.checked_add_years(years, day_change_action).unwrap()
+    }
+
+    fn checked_add_years(
+        &self,
+        years: i32,
+        day_change_action: DayChangeAction,
+    ) -> Result<Self, BadiDateError> {
+        let year = self.year() as i64 + years as i64;
+        if !(1..=LAST_YEAR_SUPPORTED as i64).contains(&year) {
+            return Err(BadiDateError::DateNotSupported);
         }
-        Self::with_ymd(self, year, month, day).unwrap()
+        let year = year as u16;
+        let month = self.month();
+        let number_of_days = month.number_of_days_with_rules(year, self.calendar_rules());
+        let day = match day_change_action {
+            DayChangeAction::Keep => self.day().min(number_of_days),
+            DayChangeAction::FirstInMonth => 1,
+            DayChangeAction::LastInMonth => number_of_days,
+        };
+        self.with_ymd(year, month, day)
     }
 
     fn add_days(&self, days: i32) -> Self {
+        self.checked_add_days(days).unwrap()
+    }
+
+    fn checked_add_days(&self, days: i32) -> Result<Self, BadiDateError> {
         if days == 0 {
-            return self.clone();
+            return Ok(self.clone());
         }
-        let abs_days = days.abs();
-        let positive = days > 0;
-        let mut day = self.day();
-        let mut month = self.month();
-        let mut year = self.year();
-        for _ in 0..abs_days {
-            if positive {
-                day += 1;
-                let max_day = month.number_of_days(year);
-                if day > max_day {
-                    day = 1;
-                    if let Some(m) = month.next() {
-                        month = m;
-                    } else {
-                        month = BadiMonth::first();
-                        year += 1;
-                    }
-                }
-            } else {
-                day -= 1;
-                if day < 1 {
-                    if let Some(m) = month.previous() {
-                        month = m;
-                        day = m.number_of_days(year);
-                    } else {
-                        month = BadiMonth::last();
-                        year -= 1;
-                        day = 19;
-                    }
-                }
-            }
-        }
-        self.with_ymd(year, month, day).unwrap()
+        let epoch_days = self
+            .to_epoch_days()
+            .checked_add(days)
+            .ok_or(BadiDateError::DateNotSupported)?;
+        self.with_epoch_days(epoch_days)
+    }
+
+    fn duration_until(&self, other: &T, skip_ayyamiha: bool) -> BadiDuration {
+        duration_between(self, other, skip_ayyamiha)
     }
 
     fn next_naw_ruz(&self) -> T {
-        self.with_ymd(self.year() + 1, BadiMonth::Month(1), 1)
-            .unwrap()
+        self.checked_next_naw_ruz().unwrap()
+    }
+
+    fn checked_next_naw_ruz(&self) -> Result<T, BadiDateError> {
+        let year = self
+            .year()
+            .checked_add(1)
+            .ok_or(BadiDateError::DateNotSupported)?;
+        self.with_ymd(year, BadiMonth::Baha, 1)
     }
 
     fn previous_naw_ruz(&self) -> T {
+        self.checked_previous_naw_ruz().unwrap()
+    }
+
+    fn checked_previous_naw_ruz(&self) -> Result<T, BadiDateError> {
         let year = if self.day_of_year() > 1 {
             self.year()
         } else {
-            self.year() - 1
+            self.year()
+                .checked_sub(1)
+                .ok_or(BadiDateError::DateNotSupported)?
         };
-        self.with_ymd(year, BadiMonth::Month(1), 1).unwrap()
+        self.with_ymd(year, BadiMonth::Baha, 1)
+    }
+}
+
+/// Number of months (including Ayyám-i-Há unless skipped) in a year
+pub(super) fn months_in_year(skip_ayyamiha: bool) -> i64 {
+    if skip_ayyamiha {
+        19
+    } else {
+        20
     }
 }
 
+/// 0-based position of `month` in the year; if Ayyám-i-Há is skipped, it takes the position of
+/// the month before it when moving forward (Mulk) or after it when moving backward (ʻAláʼ)
+pub(super) fn month_position(month: BadiMonth, skip_ayyamiha: bool, forward: bool) -> i64 {
+    match (month, skip_ayyamiha) {
+        (BadiMonth::AyyamIHa, true) => 17 + !forward as i64,
+        (month, true) => month.number() as i64 - 1,
+        (month, false) => month as i64,
+    }
+}
+
+/// [`BadiMonth`] at the 0-based `position` in the year
+pub(super) fn month_at_position(position: i64, skip_ayyamiha: bool) -> BadiMonth {
+    if skip_ayyamiha {
+        BadiMonth::try_from(position as u8 + 1).unwrap()
+    } else {
+        BadiMonth::iter().nth(position as usize).unwrap()
+    }
+}
+
+/// Adds `months` in constant time; with [`DayChangeAction::Keep`], the day is clamped to every
+/// Ayyám-i-Há passed through (as when stepping one month at a time)
+fn add_months<T: BadiDateLike>(
+    date: &T,
+    months: i32,
+    day_change_action: DayChangeAction,
+    skip_ayyamiha: bool,
+) -> Result<T, BadiDateError> {
+    if months == 0 {
+        return Ok(date.clone());
+    }
+    let rules = date.calendar_rules();
+    let months_in_year = months_in_year(skip_ayyamiha);
+    let start = date.year() as i64 * months_in_year
+        + month_position(date.month(), skip_ayyamiha, months > 0);
+    let end = start + months as i64;
+    let year = end.div_euclid(months_in_year);
+    if !(1..=LAST_YEAR_SUPPORTED as i64).contains(&year) {
+        return Err(BadiDateError::DateNotSupported);
+    }
+    let year = year as u16;
+    let month = month_at_position(end.rem_euclid(months_in_year), skip_ayyamiha);
+    let day = match day_change_action {
+        DayChangeAction::Keep => {
+            let mut day = date.day();
+            if !skip_ayyamiha {
+                // positions of Ayyám-i-Há passed through (excluding `start`, including `end`)
+                let (first, last) = if months > 0 {
+                    (start + 1, end)
+                } else {
+                    (end, start - 1)
+                };
+                let mut ayyamiha_year = first.div_euclid(months_in_year);
+                while day > 4 && ayyamiha_year * months_in_year + 18 <= last {
+                    if ayyamiha_year * months_in_year + 18 >= first {
+                        day = day.min(
+                            BadiMonth::AyyamIHa
+                                .number_of_days_with_rules(ayyamiha_year as u16, rules),
+                        );
+                    }
+                    ayyamiha_year += 1;
+                }
+            }
+            day.min(month.number_of_days_with_rules(year, rules))
+        }
+        DayChangeAction::FirstInMonth => 1,
+        DayChangeAction::LastInMonth => month.number_of_days_with_rules(year, rules),
+    };
+    date.with_ymd(year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{BadiDate, BadiDateOps, BadiMonth, DayChangeAction};
+    use crate::{BadiDate, BadiDateLike, BadiDateOps, BadiMonth, DayChangeAction};
     #[test]
     fn test_previous_and_previous_or_current_feast() {
-        let badi = BadiDate::new(181, BadiMonth::Month(19), 2).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Ala, 2).unwrap();
         let badi = badi.previous_feast();
-        assert_eq!(badi, BadiDate::new(181, BadiMonth::Month(19), 1).unwrap());
+        assert_eq!(badi, BadiDate::new(181, BadiMonth::Ala, 1).unwrap());
         let badi = badi.previous_feast();
-        assert_eq!(badi, BadiDate::new(181, BadiMonth::Month(18), 1).unwrap());
+        assert_eq!(badi, BadiDate::new(181, BadiMonth::Mulk, 1).unwrap());
 
         let badi = BadiDate::new(181, BadiMonth::AyyamIHa, 2).unwrap();
         assert_eq!(
             badi.previous_feast(),
-            BadiDate::new(181, BadiMonth::Month(18), 1).unwrap()
+            BadiDate::new(181, BadiMonth::Mulk, 1).unwrap()
         );
         assert_eq!(
             badi.previous_or_current_feast(),
-            BadiDate::new(181, BadiMonth::Month(18), 1).unwrap()
+            BadiDate::new(181, BadiMonth::Mulk, 1).unwrap()
         );
 
-        let badi = BadiDate::new(181, BadiMonth::Month(18), 1).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Mulk, 1).unwrap();
         assert_eq!(
             badi.previous_feast(),
-            BadiDate::new(181, BadiMonth::Month(17), 1).unwrap()
+            BadiDate::new(181, BadiMonth::Sultan, 1).unwrap()
         );
         assert_eq!(
             badi.previous_or_current_feast(),
-            BadiDate::new(181, BadiMonth::Month(18), 1).unwrap()
+            BadiDate::new(181, BadiMonth::Mulk, 1).unwrap()
         );
     }
 
     #[test]
     fn add_subtract_next_previous() {
-        let badi = BadiDate::new(181, BadiMonth::Month(1), 2).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Baha, 2).unwrap();
         assert_eq!(
             badi.next_day(),
-            BadiDate::new(181, BadiMonth::Month(1), 3).unwrap()
+            BadiDate::new(181, BadiMonth::Baha, 3).unwrap()
         );
         assert_eq!(
             badi.add_days(-2),
-            BadiDate::new(180, BadiMonth::Month(19), 19).unwrap()
+            BadiDate::new(180, BadiMonth::Ala, 19).unwrap()
         );
         assert_eq!(badi.add_days(-2).add_days(2), badi);
         assert_eq!(
@@ -243,66 +385,241 @@ mod tests {
             BadiDate::new(180, BadiMonth::AyyamIHa, 4)
                 .unwrap()
                 .add_days(1),
-            BadiDate::new(180, BadiMonth::Month(19), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Ala, 1).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 19)
-                .unwrap()
-                .add_days(5),
-            BadiDate::new(180, BadiMonth::Month(19), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Mulk, 19).unwrap().add_days(5),
+            BadiDate::new(180, BadiMonth::Ala, 1).unwrap()
         );
         assert_eq!(
             badi.next_month(true),
-            BadiDate::new(181, BadiMonth::Month(2), 2).unwrap()
+            BadiDate::new(181, BadiMonth::Jalal, 2).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
+            BadiDate::new(180, BadiMonth::Mulk, 17)
                 .unwrap()
                 .next_month(true),
-            BadiDate::new(180, BadiMonth::Month(19), 17).unwrap()
+            BadiDate::new(180, BadiMonth::Ala, 17).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
+            BadiDate::new(180, BadiMonth::Mulk, 17)
                 .unwrap()
                 .next_month(false),
             BadiDate::new(180, BadiMonth::AyyamIHa, 4).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
-                .unwrap()
-                .add_months(2, DayChangeAction::Keep, false),
-            BadiDate::new(180, BadiMonth::Month(19), 4).unwrap()
+            BadiDate::new(180, BadiMonth::Mulk, 17).unwrap().add_months(
+                2,
+                DayChangeAction::Keep,
+                false
+            ),
+            BadiDate::new(180, BadiMonth::Ala, 4).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
-                .unwrap()
-                .add_months(2, DayChangeAction::Keep, true),
-            BadiDate::new(181, BadiMonth::Month(1), 17).unwrap()
+            BadiDate::new(180, BadiMonth::Mulk, 17).unwrap().add_months(
+                2,
+                DayChangeAction::Keep,
+                true
+            ),
+            BadiDate::new(181, BadiMonth::Baha, 17).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
+            BadiDate::new(180, BadiMonth::Mulk, 17)
                 .unwrap()
                 .next_feast(),
-            BadiDate::new(180, BadiMonth::Month(19), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Ala, 1).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 1)
-                .unwrap()
-                .next_feast(),
-            BadiDate::new(180, BadiMonth::Month(19), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Mulk, 1).unwrap().next_feast(),
+            BadiDate::new(180, BadiMonth::Ala, 1).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
+            BadiDate::new(180, BadiMonth::Mulk, 17)
                 .unwrap()
                 .previous_feast(),
-            BadiDate::new(180, BadiMonth::Month(18), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Mulk, 1).unwrap()
         );
         assert_eq!(
-            BadiDate::new(180, BadiMonth::Month(18), 17)
+            BadiDate::new(180, BadiMonth::Mulk, 17)
                 .unwrap()
                 .previous_feast()
                 .previous_feast(),
-            BadiDate::new(180, BadiMonth::Month(17), 1).unwrap()
+            BadiDate::new(180, BadiMonth::Sultan, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn add_days_and_months_in_constant_time() {
+        let badi = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        let far = badi.add_days(50_000);
+        assert_eq!(far.to_epoch_days() - badi.to_epoch_days(), 50_000);
+        assert_eq!(far.add_days(-50_000), badi);
+        assert_eq!(
+            badi.add_months(19 * 500, DayChangeAction::Keep, true),
+            BadiDate::new(681, BadiMonth::Baha, 1).unwrap()
+        );
+        assert_eq!(
+            badi.add_months(-20 * 180, DayChangeAction::Keep, false),
+            BadiDate::new(1, BadiMonth::Baha, 1).unwrap()
+        );
+
+        let first = BadiDate::new(1, BadiMonth::Baha, 1).unwrap();
+        assert!(first.checked_previous_day().is_err());
+        assert!(first.checked_add_days(i32::MIN).is_err());
+        let last = BadiDate::new(1000, BadiMonth::Ala, 19).unwrap();
+        assert!(last.checked_next_day().is_err());
+        assert!(last.checked_add_days(i32::MAX).is_err());
+        assert_eq!(
+            last.checked_previous_day().unwrap(),
+            BadiDate::new(1000, BadiMonth::Ala, 18).unwrap()
+        );
+        assert_eq!(first.checked_add_days(last.to_epoch_days()).unwrap(), last);
+    }
+
+    #[test]
+    fn add_months_matches_stepping() {
+        for (year, month, day) in [
+            (180, BadiMonth::Sultan, 19),
+            (180, BadiMonth::AyyamIHa, 4),
+            (181, BadiMonth::Ala, 7),
+            (183, BadiMonth::Baha, 5),
+        ] {
+            let badi = BadiDate::new(year, month, day).unwrap();
+            for skip_ayyamiha in [false, true] {
+                for action in [
+                    DayChangeAction::Keep,
+                    DayChangeAction::FirstInMonth,
+                    DayChangeAction::LastInMonth,
+                ] {
+                    let mut forward = badi.clone();
+                    let mut backward = badi.clone();
+                    for months in 1..=45 {
+                        forward = forward.add_months(1, action, skip_ayyamiha);
+                        backward = backward.add_months(-1, action, skip_ayyamiha);
+                        assert_eq!(badi.add_months(months, action, skip_ayyamiha), forward);
+                        assert_eq!(badi.add_months(-months, action, skip_ayyamiha), backward);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_days_matches_stepping() {
+        let badi = BadiDate::new(179, BadiMonth::Mulk, 10).unwrap();
+        let mut forward = badi.clone();
+        let mut backward = badi.clone();
+        for days in 1..=800 {
+            forward = forward.next_day();
+            backward = backward.previous_day();
+            assert_eq!(badi.add_days(days), forward);
+            assert_eq!(badi.add_days(-days), backward);
+        }
+    }
+
+    #[test]
+    fn checked_at_range_boundaries() {
+        let first = BadiDate::new(1, BadiMonth::Baha, 1).unwrap();
+        assert!(first.checked_previous_naw_ruz().is_err());
+        assert!(first.checked_previous_feast().is_err());
+        assert!(first.checked_previous_month(true).is_err());
+        assert!(first.checked_previous_month(false).is_err());
+        assert!(first
+            .checked_add_months(-1, DayChangeAction::Keep, true)
+            .is_err());
+        assert!(first
+            .checked_add_months(i32::MIN, DayChangeAction::Keep, false)
+            .is_err());
+        assert_eq!(first.checked_previous_or_current_feast().unwrap(), first);
+        assert_eq!(
+            first.checked_next_naw_ruz().unwrap(),
+            BadiDate::new(2, BadiMonth::Baha, 1).unwrap()
+        );
+        let second = first.next_day();
+        assert_eq!(second.checked_previous_naw_ruz().unwrap(), first);
+        assert_eq!(second.checked_previous_feast().unwrap(), first);
+        assert_eq!(second.checked_previous_or_current_feast().unwrap(), first);
+
+        let last = BadiDate::new(1000, BadiMonth::Ala, 19).unwrap();
+        assert!(last.checked_next_naw_ruz().is_err());
+        assert!(last.checked_next_feast().is_err());
+        assert!(last.checked_next_month(true).is_err());
+        assert!(last.checked_next_month(false).is_err());
+        assert!(last
+            .checked_add_months(i32::MAX, DayChangeAction::Keep, true)
+            .is_err());
+        assert_eq!(
+            last.checked_previous_naw_ruz().unwrap(),
+            BadiDate::new(1000, BadiMonth::Baha, 1).unwrap()
+        );
+        assert_eq!(
+            last.checked_previous_feast().unwrap(),
+            BadiDate::new(1000, BadiMonth::Ala, 1).unwrap()
+        );
+        assert_eq!(
+            last.checked_previous_month(false).unwrap(),
+            BadiDate::new(
+                1000,
+                BadiMonth::AyyamIHa,
+                BadiMonth::AyyamIHa.number_of_days(1000)
+            )
+            .unwrap()
+        );
+        let last_feast = BadiDate::new(1000, BadiMonth::Mulk, 3).unwrap();
+        assert_eq!(
+            last_feast.checked_next_feast().unwrap(),
+            BadiDate::new(1000, BadiMonth::Ala, 1).unwrap()
+        );
+
+        let naw_ruz = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        assert_eq!(naw_ruz.checked_next_feast().unwrap(), naw_ruz.next_feast());
+        assert_eq!(
+            naw_ruz.checked_previous_naw_ruz().unwrap(),
+            BadiDate::new(180, BadiMonth::Baha, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn add_years() {
+        let badi = BadiDate::new(181, BadiMonth::Kalimat, 12).unwrap();
+        assert_eq!(
+            badi.add_years(19, DayChangeAction::Keep),
+            BadiDate::new(200, BadiMonth::Kalimat, 12).unwrap()
+        );
+        assert_eq!(
+            badi.add_years(-180, DayChangeAction::FirstInMonth),
+            BadiDate::new(1, BadiMonth::Kalimat, 1).unwrap()
+        );
+        assert_eq!(
+            badi.add_years(1, DayChangeAction::LastInMonth),
+            BadiDate::new(182, BadiMonth::Kalimat, 19).unwrap()
+        );
+        assert_eq!(badi.add_years(0, DayChangeAction::Keep), badi);
+        assert!(badi.checked_add_years(-181, DayChangeAction::Keep).is_err());
+        assert!(badi.checked_add_years(820, DayChangeAction::Keep).is_err());
+        assert!(badi
+            .checked_add_years(i32::MAX, DayChangeAction::Keep)
+            .is_err());
+        assert!(badi.checked_add_years(819, DayChangeAction::Keep).is_ok());
+
+        // anniversaries of 5 Ayyám-i-Há in years with 4 days of Ayyám-i-Há
+        let leap_year = (172..=200)
+            .find(|&year| BadiMonth::AyyamIHa.number_of_days(year) == 5)
+            .unwrap();
+        let ayyamiha = BadiDate::new(leap_year, BadiMonth::AyyamIHa, 5).unwrap();
+        assert_eq!(BadiMonth::AyyamIHa.number_of_days(leap_year + 1), 4);
+        assert_eq!(
+            ayyamiha.add_years(1, DayChangeAction::Keep),
+            BadiDate::new(leap_year + 1, BadiMonth::AyyamIHa, 4).unwrap()
+        );
+        assert_eq!(
+            ayyamiha.add_years(1, DayChangeAction::LastInMonth),
+            BadiDate::new(leap_year + 1, BadiMonth::AyyamIHa, 4).unwrap()
+        );
+        assert_eq!(
+            ayyamiha.add_years(-1, DayChangeAction::FirstInMonth),
+            BadiDate::new(leap_year - 1, BadiMonth::AyyamIHa, 1).unwrap()
         );
+        assert!(ayyamiha.with_year(leap_year + 1).is_err());
     }
 }
@@ -0,0 +1,347 @@
+use std::iter::FusedIterator;
+
+use super::badi_date_ops::{month_at_position, month_position, months_in_year};
+use crate::{BadiDateLike, BadiMonth, LAST_YEAR_SUPPORTED};
+
+/// An inclusive range of [`BadiDateLike`]s from `start` to `end` (empty if `end` is before `start`)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BadiDateRange<T: BadiDateLike> {
+    start: T,
+    end: T,
+}
+
+impl<T: BadiDateLike> BadiDateRange<T> {
+    /// Create a new [`BadiDateRange`] from `start` to `end` (inclusive)
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// First day of the range
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Last day of the range
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// Whether the range contains no days (`end` is before `start`)
+    pub fn is_empty(&self) -> bool {
+        self.start.days_until(&self.end) < 0
+    }
+
+    /// Whether `date` is in the range
+    pub fn contains<O: BadiDateLike>(&self, date: &O) -> bool {
+        self.start.days_until(date) >= 0 && date.days_until(&self.end) >= 0
+    }
+
+    /// Iterates over every day in the range (**includes** [`BadiMonth::AyyamIHa`])
+    pub fn iter_days(&self) -> BadiDateRangeDays<T> {
+        let front = self.start.to_epoch_days();
+        // `end` may count days under other calendar rules than `start`, so stay within the supported years of `start`
+        let last = self
+            .start
+            .with_ymd(LAST_YEAR_SUPPORTED, BadiMonth::Ala, 19)
+            .expect("the last day of the last supported year is valid")
+            .to_epoch_days();
+        BadiDateRangeDays {
+            template: self.start.clone(),
+            front,
+            back: (front + self.start.days_until(&self.end).max(-1) + 1).min(last + 1),
+        }
+    }
+
+    /// Iterates over the first day of every month from the month of `start` to the month of `end`
+    /// * `skip_ayyamiha` - whether to skip over the "month" of Ayyám-i-Há
+    pub fn iter_months(&self, skip_ayyamiha: bool) -> BadiDateRangeMonths<T> {
+        let months_in_year = months_in_year(skip_ayyamiha);
+        let front = self.start.year() as i64 * months_in_year
+            + month_position(self.start.month(), skip_ayyamiha, false);
+        let back = self.end.year() as i64 * months_in_year
+            + month_position(self.end.month(), skip_ayyamiha, true)
+            + 1;
+        BadiDateRangeMonths::new(
+            self.start.clone(),
+            front,
+            back,
+            skip_ayyamiha,
+            self.is_empty(),
+        )
+    }
+
+    /// Iterates over every Feast (day 1 of each [`BadiMonth`] except Ayyám-i-Há) in the range
+    pub fn iter_feasts(&self) -> BadiDateRangeMonths<T> {
+        let mut front =
+            self.start.year() as i64 * 19 + month_position(self.start.month(), true, false);
+        if self.start.day() > 1 && self.start.month() != BadiMonth::AyyamIHa {
+            front += 1;
+        }
+        let back = self.end.year() as i64 * 19 + month_position(self.end.month(), true, true) + 1;
+        BadiDateRangeMonths::new(self.start.clone(), front, back, true, self.is_empty())
+    }
+}
+
+/// Iterator over the days of a [`BadiDateRange`] (see [`BadiDateRange::iter_days`])
+#[derive(Clone, Debug)]
+pub struct BadiDateRangeDays<T: BadiDateLike> {
+    template: T,
+    front: i32,
+    back: i32,
+}
+
+impl<T: BadiDateLike> BadiDateRangeDays<T> {
+    fn day(&self, epoch_days: i32) -> T {
+        self.template
+            .with_epoch_days(epoch_days)
+            .expect("the days of the range are clamped to the supported years")
+    }
+}
+
+impl<T: BadiDateLike> Iterator for BadiDateRangeDays<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.day(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self
+            .front
+            .saturating_add(n.min(i32::MAX as usize) as i32)
+            .min(self.back);
+        self.next()
+    }
+}
+
+impl<T: BadiDateLike> DoubleEndedIterator for BadiDateRangeDays<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.day(self.back))
+    }
+}
+
+impl<T: BadiDateLike> ExactSizeIterator for BadiDateRangeDays<T> {}
+
+impl<T: BadiDateLike> FusedIterator for BadiDateRangeDays<T> {}
+
+/// Iterator over the first days of months of a [`BadiDateRange`]
+/// (see [`BadiDateRange::iter_months`] and [`BadiDateRange::iter_feasts`])
+#[derive(Clone, Debug)]
+pub struct BadiDateRangeMonths<T: BadiDateLike> {
+    template: T,
+    front: i64,
+    back: i64,
+    skip_ayyamiha: bool,
+}
+
+impl<T: BadiDateLike> BadiDateRangeMonths<T> {
+    fn new(template: T, front: i64, back: i64, skip_ayyamiha: bool, empty: bool) -> Self {
+        Self {
+            template,
+            front,
+            back: if empty { front } else { back.max(front) },
+            skip_ayyamiha,
+        }
+    }
+
+    fn first_day_of_month(&self, position: i64) -> Option<T> {
+        let months_in_year = months_in_year(self.skip_ayyamiha);
+        let year = u16::try_from(position.div_euclid(months_in_year)).ok()?;
+        let month = month_at_position(position.rem_euclid(months_in_year), self.skip_ayyamiha);
+        self.template.with_ymd(year, month, 1).ok()
+    }
+}
+
+impl<T: BadiDateLike> Iterator for BadiDateRangeMonths<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        self.first_day_of_month(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T: BadiDateLike> DoubleEndedIterator for BadiDateRangeMonths<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.first_day_of_month(self.back)
+    }
+}
+
+impl<T: BadiDateLike> ExactSizeIterator for BadiDateRangeMonths<T> {}
+
+impl<T: BadiDateLike> FusedIterator for BadiDateRangeMonths<T> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Tz;
+
+    use crate::{
+        BadiDate, BadiDateLike, BadiDateOps, BadiDateRange, BadiMonth, CalendarRules,
+        LocalBadiDate, LocalBadiDateLike, LAST_YEAR_SUPPORTED,
+    };
+
+    #[test]
+    fn iter_days() {
+        // 180 B.E. has 4 days of Ayyám-i-Há
+        let start = BadiDate::new(180, BadiMonth::Mulk, 18).unwrap();
+        let end = BadiDate::new(180, BadiMonth::Ala, 2).unwrap();
+        let range = BadiDateRange::new(start.clone(), end.clone());
+        let days: Vec<BadiDate> = range.iter_days().collect();
+        assert_eq!(range.iter_days().len(), 8);
+        assert_eq!(days.len(), 8);
+        assert_eq!(days[0], start);
+        assert_eq!(days[2], BadiDate::new(180, BadiMonth::AyyamIHa, 1).unwrap());
+        assert_eq!(days[7], end);
+        assert!(days.windows(2).all(|pair| pair[0].next_day() == pair[1]));
+        let reversed: Vec<BadiDate> = range.iter_days().rev().collect();
+        assert_eq!(reversed, days.iter().rev().cloned().collect::<Vec<_>>());
+        assert!(range.contains(&days[3]));
+        assert!(!range.contains(&end.next_day()));
+
+        let mut iter = range.iter_days();
+        assert_eq!(iter.next(), Some(start.clone()));
+        assert_eq!(iter.next_back(), Some(end.clone()));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.nth(5), Some(end.previous_day()));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let year = BadiDateRange::new(
+            BadiDate::new(181, BadiMonth::first(), 1).unwrap(),
+            BadiDate::new(181, BadiMonth::last(), 19).unwrap(),
+        );
+        assert_eq!(year.iter_days().count(), 365);
+
+        let empty = BadiDateRange::new(end.clone(), start.clone());
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter_days().len(), 0);
+        assert_eq!(empty.iter_months(false).len(), 0);
+        assert_eq!(empty.iter_feasts().len(), 0);
+        let single = BadiDateRange::new(start.clone(), start.clone());
+        assert!(!single.is_empty());
+        assert_eq!(single.iter_days().collect::<Vec<_>>(), vec![start]);
+
+        // an `end` under other rules may fall after the last supported day under the rules of `start`
+        for (start_rules, end_rules) in [
+            (
+                CalendarRules::Unified2015,
+                CalendarRules::WesternGregorianPinned,
+            ),
+            (
+                CalendarRules::WesternGregorianPinned,
+                CalendarRules::Unified2015,
+            ),
+        ] {
+            let last =
+                BadiDate::new_with_rules(LAST_YEAR_SUPPORTED, BadiMonth::Ala, 19, start_rules)
+                    .unwrap();
+            let start = last.with_day(10).unwrap();
+            let end = BadiDate::new_with_rules(LAST_YEAR_SUPPORTED, BadiMonth::Ala, 19, end_rules)
+                .unwrap();
+            let range = BadiDateRange::new(start.clone(), end.clone());
+            let days: Vec<BadiDate> = range.iter_days().collect();
+            assert_eq!(days.len(), range.iter_days().len());
+            assert!(days.len() <= 10);
+            assert_eq!(days.first(), Some(&start));
+            assert_eq!(
+                range.iter_days().rev().collect::<Vec<_>>(),
+                days.iter().rev().cloned().collect::<Vec<_>>()
+            );
+            assert!(days.last().unwrap().days_until(&end) >= 0);
+        }
+    }
+
+    #[test]
+    fn iter_months_and_feasts() {
+        let start = BadiDate::new(180, BadiMonth::Sultan, 5).unwrap();
+        let end = BadiDate::new(181, BadiMonth::Jalal, 1).unwrap();
+        let range = BadiDateRange::new(start, end);
+        let months: Vec<BadiMonth> = range.iter_months(false).map(|date| date.month()).collect();
+        assert_eq!(
+            months,
+            vec![
+                BadiMonth::Sultan,
+                BadiMonth::Mulk,
+                BadiMonth::AyyamIHa,
+                BadiMonth::Ala,
+                BadiMonth::Baha,
+                BadiMonth::Jalal,
+            ]
+        );
+        assert_eq!(range.iter_months(false).len(), 6);
+        assert_eq!(range.iter_months(true).len(), 5);
+        assert!(range.iter_months(false).all(|date| date.day() == 1));
+        assert_eq!(
+            range.iter_months(true).next_back(),
+            Some(BadiDate::new(181, BadiMonth::Jalal, 1).unwrap())
+        );
+
+        let feasts: Vec<BadiDate> = range.iter_feasts().collect();
+        assert_eq!(
+            feasts,
+            vec![
+                BadiDate::new(180, BadiMonth::Mulk, 1).unwrap(),
+                BadiDate::new(180, BadiMonth::Ala, 1).unwrap(),
+                BadiDate::new(181, BadiMonth::Baha, 1).unwrap(),
+                BadiDate::new(181, BadiMonth::Jalal, 1).unwrap(),
+            ]
+        );
+        assert!(feasts.iter().all(|date| date.is_feast()));
+        assert_eq!(range.iter_feasts().len(), 4);
+        assert_eq!(
+            range.iter_feasts().rev().collect::<Vec<_>>(),
+            feasts.iter().rev().cloned().collect::<Vec<_>>()
+        );
+
+        // from and to Ayyám-i-Há
+        let start = BadiDate::new(180, BadiMonth::AyyamIHa, 2).unwrap();
+        let end = BadiDate::new(181, BadiMonth::AyyamIHa, 1).unwrap();
+        let range = BadiDateRange::new(start, end);
+        let feasts: Vec<BadiDate> = range.iter_feasts().collect();
+        assert_eq!(feasts.len(), 19);
+        assert_eq!(feasts[0], BadiDate::new(180, BadiMonth::Ala, 1).unwrap());
+        assert_eq!(feasts[18], BadiDate::new(181, BadiMonth::Mulk, 1).unwrap());
+        assert_eq!(range.iter_months(true).count(), 19);
+        assert_eq!(range.iter_months(false).count(), 21);
+        assert_eq!(
+            range.iter_months(false).next().unwrap(),
+            BadiDate::new(180, BadiMonth::AyyamIHa, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn local_range() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let start = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap();
+        let end = LocalBadiDate::new(181, BadiMonth::Jamal, 19, denver, None).unwrap();
+        let range = BadiDateRange::new(start, end);
+        assert_eq!(range.iter_days().len(), 57);
+        assert!(range.iter_days().all(|date| date.timezone() == denver));
+        assert_eq!(range.iter_months(true).len(), 3);
+    }
+}
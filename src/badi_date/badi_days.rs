@@ -0,0 +1,258 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use chrono::{Days, NaiveDate, TimeDelta};
+use serde::{Deserialize, Serialize};
+
+use crate::{BadiDate, BadiDateLike, BadiDateOps, LocalBadiDate};
+
+/// A signed number of days to add to or subtract from a [`BadiDate`] or [`LocalBadiDate`] with the
+/// `+` and `-` operators (unlike [`chrono::Days`], it may be negative)
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct BadiDays(pub i32);
+
+impl BadiDays {
+    /// Create a new [`BadiDays`] from a number of days (negative to go back in time)
+    pub const fn new(days: i32) -> Self {
+        Self(days)
+    }
+
+    /// Number of days
+    pub const fn num_days(&self) -> i32 {
+        self.0
+    }
+}
+
+impl From<i32> for BadiDays {
+    fn from(days: i32) -> Self {
+        Self(days)
+    }
+}
+
+impl From<BadiDays> for i32 {
+    fn from(days: BadiDays) -> Self {
+        days.0
+    }
+}
+
+impl From<BadiDays> for TimeDelta {
+    fn from(days: BadiDays) -> Self {
+        TimeDelta::days(days.0 as i64)
+    }
+}
+
+/// Fails if the number of days is negative
+impl TryFrom<BadiDays> for Days {
+    type Error = TryFromIntError;
+
+    fn try_from(days: BadiDays) -> Result<Self, Self::Error> {
+        Ok(Days::new(u64::try_from(days.0)?))
+    }
+}
+
+impl Neg for BadiDays {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Add for BadiDays {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for BadiDays {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+/// Fails if the number of days is more than chrono can count between its earliest and latest dates (about 190
+/// million, far more than the supported range)
+impl TryFrom<Days> for BadiDays {
+    type Error = TryFromIntError;
+
+    fn try_from(days: Days) -> Result<Self, Self::Error> {
+        // `Days` has no accessor: count them from the earliest date
+        let num_days = NaiveDate::MIN
+            .checked_add_days(days)
+            .map_or(i64::MAX, |date| (date - NaiveDate::MIN).num_days());
+        Ok(Self(i32::try_from(num_days)?))
+    }
+}
+
+/// Operators adding or subtracting `$days` (converted to [`BadiDays`]) for a [`BadiDateLike`]
+macro_rules! impl_add_sub_days {
+    ($date:ty, $days:ty) => {
+        impl Add<$days> for $date {
+            type Output = $date;
+
+            fn add(self, rhs: $days) -> Self::Output {
+                self.add_days(num_days(rhs))
+            }
+        }
+
+        impl Add<$days> for &$date {
+            type Output = $date;
+
+            fn add(self, rhs: $days) -> Self::Output {
+                self.add_days(num_days(rhs))
+            }
+        }
+
+        impl Sub<$days> for $date {
+            type Output = $date;
+
+            fn sub(self, rhs: $days) -> Self::Output {
+                self.add_days(-num_days(rhs))
+            }
+        }
+
+        impl Sub<$days> for &$date {
+            type Output = $date;
+
+            fn sub(self, rhs: $days) -> Self::Output {
+                self.add_days(-num_days(rhs))
+            }
+        }
+
+        impl AddAssign<$days> for $date {
+            fn add_assign(&mut self, rhs: $days) {
+                *self = self.add_days(num_days(rhs));
+            }
+        }
+
+        impl SubAssign<$days> for $date {
+            fn sub_assign(&mut self, rhs: $days) {
+                *self = self.add_days(-num_days(rhs));
+            }
+        }
+    };
+}
+
+/// Number of days in [`BadiDays`] or [`Days`]; panics if [`Days`] do not fit in an `i32` (far outside the supported
+/// range anyway)
+fn num_days<D: TryInto<BadiDays>>(days: D) -> i32
+where
+    D::Error: std::fmt::Debug,
+{
+    days.try_into()
+        .expect("the number of days is far outside the supported range")
+        .0
+}
+
+/// Operators for a [`BadiDateLike`]; adding or subtracting days panics if the result is not in the supported range
+/// (use [`BadiDateOps::checked_add_days`] to handle it)
+macro_rules! impl_days_ops {
+    ($date:ty) => {
+        impl_add_sub_days!($date, BadiDays);
+        impl_add_sub_days!($date, Days);
+
+        /// Number of days from `rhs` until `self` (see [`BadiDateLike::days_until`])
+        impl Sub for $date {
+            type Output = BadiDays;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                BadiDays(rhs.days_until(&self))
+            }
+        }
+
+        /// Number of days from `rhs` until `self` (see [`BadiDateLike::days_until`])
+        impl Sub for &$date {
+            type Output = BadiDays;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                BadiDays(rhs.days_until(self))
+            }
+        }
+    };
+}
+
+impl_days_ops!(BadiDate);
+impl_days_ops!(LocalBadiDate);
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, TimeDelta};
+    use chrono_tz::Tz;
+
+    use crate::{BadiDate, BadiDateOps, BadiDays, BadiMonth, LocalBadiDate};
+
+    #[test]
+    fn operators() {
+        let date = BadiDate::new(180, BadiMonth::Mulk, 19).unwrap();
+        let feast = BadiDate::new(180, BadiMonth::Ala, 1).unwrap();
+        assert_eq!(&date + BadiDays(5), feast);
+        assert_eq!(date.clone() + BadiDays(5), feast);
+        assert_eq!(&feast - BadiDays(5), date);
+        assert_eq!(feast.clone() + BadiDays(-5), date);
+        assert_eq!(&feast - &date, BadiDays(5));
+        assert_eq!(date.clone() - feast.clone(), BadiDays(-5));
+        assert_eq!(date.clone() + Days::new(5), feast);
+        assert_eq!(feast.clone() - Days::new(5), date);
+        assert_eq!(&date + Days::new(5), feast);
+        assert_eq!(&feast - Days::new(5), date);
+
+        let mut badi_date = date.clone();
+        badi_date += BadiDays(5);
+        assert_eq!(badi_date, feast);
+        badi_date -= BadiDays(5);
+        assert_eq!(badi_date, date);
+        badi_date += Days::new(5);
+        assert_eq!(badi_date, feast);
+        badi_date -= Days::new(5);
+        assert_eq!(badi_date, date);
+        for days in [-400, -19, -1, 0, 1, 4, 19, 366, 5000] {
+            assert_eq!(&date + BadiDays(days), date.add_days(days));
+            assert_eq!((&date + BadiDays(days)) - date.clone(), BadiDays(days));
+            if let Ok(chrono_days) = Days::try_from(BadiDays(days)) {
+                assert_eq!(date.clone() + chrono_days, date.add_days(days));
+            }
+        }
+
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let local = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap();
+        let mut next = &local + BadiDays(19);
+        assert_eq!(
+            next,
+            LocalBadiDate::new(181, BadiMonth::Jalal, 1, denver, None).unwrap()
+        );
+        assert_eq!(&next - &local, BadiDays(19));
+        next -= Days::new(19);
+        assert_eq!(next, local);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(BadiDays::new(3).num_days(), 3);
+        assert_eq!(BadiDays::from(-3), -BadiDays(3));
+        assert_eq!(i32::from(BadiDays(3) + BadiDays(4) - BadiDays(1)), 6);
+        assert_eq!(TimeDelta::from(BadiDays(-2)), TimeDelta::days(-2));
+        assert_eq!(Days::try_from(BadiDays(2)).unwrap(), Days::new(2));
+        assert!(Days::try_from(BadiDays(-2)).is_err());
+        assert_eq!(BadiDays::try_from(Days::new(2)).unwrap(), BadiDays(2));
+        assert_eq!(
+            BadiDays::try_from(Days::new(100_000_000)).unwrap(),
+            BadiDays(100_000_000)
+        );
+        assert!(BadiDays::try_from(Days::new(i32::MAX as u64)).is_err());
+        assert!(BadiDays::try_from(Days::new(u64::MAX)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_panics() {
+        let _ = BadiDate::new(1, BadiMonth::Baha, 1).unwrap() - BadiDays(1);
+    }
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use super::badi_date_ops::{month_position, months_in_year};
+use crate::{BadiDateError, BadiDateLike, BadiDateOps, DayChangeAction};
+
+/// A calendar-aware difference between two [`BadiDateLike`]s in years, months, and days
+///
+/// Adding `years * months_per_year + months` months (with [`DayChangeAction::Keep`]), then `days` days,
+/// to the earlier date gives the later date, where a year has 19 months if Ayyám-i-Há is skipped
+/// and 20 "months" otherwise. All fields have the same sign (negative if going back in time).
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct BadiDuration {
+    /// Number of whole years
+    pub years: i32,
+    /// Number of whole months after `years` (Ayyám-i-Há counts as a month unless `skip_ayyamiha`)
+    pub months: i32,
+    /// Number of days after `years` and `months`
+    pub days: i32,
+    /// Whether Ayyám-i-Há was skipped when counting months (see [`BadiDateOps::add_months`])
+    pub skip_ayyamiha: bool,
+}
+
+impl BadiDuration {
+    /// Total number of months (`years` converted to months plus `months`)
+    pub fn total_months(&self) -> i32 {
+        self.years * months_in_year(self.skip_ayyamiha) as i32 + self.months
+    }
+
+    /// Returns new [`BadiDateLike`] with this duration added
+    /// (or [`BadiDateError::DateNotSupported`] if the result is not in the supported range)
+    pub fn add_to<T: BadiDateLike>(&self, date: &T) -> Result<T, BadiDateError> {
+        date.checked_add_months(
+            self.total_months(),
+            DayChangeAction::Keep,
+            self.skip_ayyamiha,
+        )?
+        .checked_add_days(self.days)
+    }
+}
+
+/// Computes the [`BadiDuration`] from `start` to `end`
+pub(crate) fn duration_between<T: BadiDateLike>(
+    start: &T,
+    end: &T,
+    skip_ayyamiha: bool,
+) -> BadiDuration {
+    let total_days = start.days_until(end);
+    let forward = total_days >= 0;
+    let mut months = (month_position(end.month(), skip_ayyamiha, forward)
+        - month_position(start.month(), skip_ayyamiha, forward)
+        + (end.year() as i64 - start.year() as i64) * months_in_year(skip_ayyamiha))
+        as i32;
+    let add_months = |months: i32| {
+        start
+            .checked_add_months(months, DayChangeAction::Keep, skip_ayyamiha)
+            .ok()
+    };
+    let mut intermediate = add_months(months);
+    // step back one month if the day of `start` is past the day of `end`
+    if intermediate
+        .as_ref()
+        .filter(|&date| start.days_until(date) * total_days.signum() <= total_days.abs())
+        .is_none()
+    {
+        months -= total_days.signum();
+        intermediate = add_months(months);
+    }
+    let days = intermediate
+        .map(|date| date.days_until(end))
+        .unwrap_or(total_days);
+    let months_in_year = months_in_year(skip_ayyamiha) as i32;
+    BadiDuration {
+        years: months / months_in_year,
+        months: months % months_in_year,
+        days,
+        skip_ayyamiha,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BadiDate, BadiDateLike, BadiDateOps, BadiDays, BadiDuration, BadiMonth};
+
+    #[test]
+    fn days_until_and_sub() {
+        let naw_ruz = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        let feast = BadiDate::new(181, BadiMonth::Jalal, 1).unwrap();
+        assert_eq!(naw_ruz.days_until(&feast), 19);
+        assert_eq!(feast.days_until(&naw_ruz), -19);
+        assert_eq!(&feast - &naw_ruz, BadiDays(19));
+        assert_eq!(naw_ruz.clone() - feast.clone(), BadiDays(-19));
+
+        // 180 B.E. has 4 days of Ayyám-i-Há
+        let before = BadiDate::new(180, BadiMonth::Mulk, 19).unwrap();
+        let after = BadiDate::new(180, BadiMonth::Ala, 1).unwrap();
+        assert_eq!(before.days_until(&after), 5);
+        assert_eq!(
+            before.days_until(&BadiDate::new(181, BadiMonth::Baha, 1).unwrap()),
+            24
+        );
+    }
+
+    #[test]
+    fn duration_until() {
+        let birth = BadiDate::new(150, BadiMonth::Kalimat, 12).unwrap();
+        let today = BadiDate::new(181, BadiMonth::Kalimat, 11).unwrap();
+        let age = birth.duration_until(&today, true);
+        assert_eq!(
+            age,
+            BadiDuration {
+                years: 30,
+                months: 18,
+                days: 18,
+                skip_ayyamiha: true
+            }
+        );
+        let birthday = today.next_day();
+        assert_eq!(birth.duration_until(&birthday, true).years, 31);
+        assert_eq!(birth.duration_until(&birthday, true).days, 0);
+        // counting Ayyám-i-Há as a month clamps the day to it (as in `add_months`)
+        assert_eq!(
+            birth.duration_until(&birthday, false),
+            BadiDuration {
+                years: 31,
+                months: 0,
+                days: 8,
+                skip_ayyamiha: false
+            }
+        );
+
+        // spanning Ayyám-i-Há
+        let start = BadiDate::new(180, BadiMonth::Mulk, 10).unwrap();
+        let end = BadiDate::new(180, BadiMonth::Ala, 12).unwrap();
+        assert_eq!(
+            start.duration_until(&end, true),
+            BadiDuration {
+                years: 0,
+                months: 1,
+                days: 2,
+                skip_ayyamiha: true
+            }
+        );
+        assert_eq!(
+            start.duration_until(&end, false),
+            BadiDuration {
+                years: 0,
+                months: 2,
+                days: 8,
+                skip_ayyamiha: false
+            }
+        );
+        assert_eq!(
+            end.duration_until(&start, true),
+            BadiDuration {
+                years: 0,
+                months: -1,
+                days: -2,
+                skip_ayyamiha: true
+            }
+        );
+
+        // "days until the next Feast"
+        let feast = start.next_feast();
+        assert_eq!(start.days_until(&feast), 14);
+        assert_eq!(
+            start.duration_until(&feast, true),
+            BadiDuration {
+                years: 0,
+                months: 0,
+                days: 14,
+                skip_ayyamiha: true
+            }
+        );
+
+        let dates: Vec<BadiDate> = (0..40)
+            .map(|n| {
+                BadiDate::new(178, BadiMonth::Sultan, 19)
+                    .unwrap()
+                    .add_days(n * 23)
+            })
+            .collect();
+        for start in &dates {
+            for end in &dates {
+                for skip_ayyamiha in [false, true] {
+                    let duration = start.duration_until(end, skip_ayyamiha);
+                    assert_eq!(&duration.add_to(start).unwrap(), end);
+                    let signs = [duration.years, duration.months, duration.days].map(i32::signum);
+                    assert!(!signs.contains(&1) || !signs.contains(&-1), "{duration:?}");
+                    assert!(duration.months.abs() < 20);
+                    assert!(duration.days.abs() < 25);
+                }
+            }
+        }
+    }
+}
@@ -1,109 +1,281 @@
-use rust_i18n::t;
-use serde::{Deserialize, Serialize};
+use std::fmt;
 
-use crate::BadiDateError;
+use rust_i18n::t;
+use serde::{
+    de::{self, EnumAccess, MapAccess, VariantAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
-use super::util::*;
+use crate::{BadiDateError, CalendarRules};
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
-/// Represents one of the 19 Bahá’í months or Ayyám-i-Há
+/// Represents one of the 19 Bahá’í months or Ayyám-i-Há, in calendar order (Ayyám-i-Há falls between Mulk and ʻAláʼ)
+///
+/// Human-readable formats deserialize from a variant name, a month name
+/// (see [`BadiMonth::from_str`][`std::str::FromStr::from_str`]), a month number ([1 - 19], or 0 for Ayyám-i-Há),
+/// or the legacy `{"Month": 1}` form; other formats (e.g. bincode, postcard) use the variant as serialized.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum BadiMonth {
-    /// One of the 19 Badi/Bahá’í months (parameter is 1-based month number)
-    Month(u8),
-    /// The intercalary days of Ayyám-i-Há
+    /// Bahá (Splendour), month 1
+    Baha,
+    /// Jalál (Glory), month 2
+    Jalal,
+    /// Jamál (Beauty), month 3
+    Jamal,
+    /// ʻAẓamat (Grandeur), month 4
+    Azamat,
+    /// Núr (Light), month 5
+    Nur,
+    /// Raḥmat (Mercy), month 6
+    Rahmat,
+    /// Kalimát (Words), month 7
+    Kalimat,
+    /// Kamál (Perfection), month 8
+    Kamal,
+    /// Asmáʼ (Names), month 9
+    Asma,
+    /// ʻIzzat (Might), month 10
+    Izzat,
+    /// Mashíyyat (Will), month 11
+    Mashiyyat,
+    /// ʻIlm (Knowledge), month 12
+    Ilm,
+    /// Qudrat (Power), month 13
+    Qudrat,
+    /// Qawl (Speech), month 14
+    Qawl,
+    /// Masáʼil (Questions), month 15
+    Masail,
+    /// Sharaf (Honour), month 16
+    Sharaf,
+    /// Sulṭán (Sovereignty), month 17
+    Sultan,
+    /// Mulk (Dominion), month 18
+    Mulk,
+    /// The intercalary days of Ayyám-i-Há (between Mulk and ʻAláʼ)
     AyyamIHa,
+    /// ʻAláʼ (Loftiness), month 19, the month of fasting
+    Ala,
 }
 
-impl Ord for BadiMonth {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self {
-            BadiMonth::Month(sm) => match other {
-                BadiMonth::Month(om) => sm.cmp(om),
-                BadiMonth::AyyamIHa => {
-                    if *sm == 19 {
-                        std::cmp::Ordering::Greater
-                    } else {
-                        std::cmp::Ordering::Less
-                    }
-                }
-            },
-            BadiMonth::AyyamIHa => match other {
-                BadiMonth::Month(om) => {
-                    if *om == 19 {
-                        std::cmp::Ordering::Less
-                    } else {
-                        std::cmp::Ordering::Greater
-                    }
-                }
-                BadiMonth::AyyamIHa => std::cmp::Ordering::Equal,
-            },
+/// All 19 months and Ayyám-i-Há in calendar order
+const MONTHS: [BadiMonth; 20] = [
+    BadiMonth::Baha,
+    BadiMonth::Jalal,
+    BadiMonth::Jamal,
+    BadiMonth::Azamat,
+    BadiMonth::Nur,
+    BadiMonth::Rahmat,
+    BadiMonth::Kalimat,
+    BadiMonth::Kamal,
+    BadiMonth::Asma,
+    BadiMonth::Izzat,
+    BadiMonth::Mashiyyat,
+    BadiMonth::Ilm,
+    BadiMonth::Qudrat,
+    BadiMonth::Qawl,
+    BadiMonth::Masail,
+    BadiMonth::Sharaf,
+    BadiMonth::Sultan,
+    BadiMonth::Mulk,
+    BadiMonth::AyyamIHa,
+    BadiMonth::Ala,
+];
+
+/// Accepts a month number [1 - 19], or 0 for Ayyám-i-Há (otherwise [`BadiDateError::MonthInvalid`])
+impl TryFrom<u8> for BadiMonth {
+    type Error = BadiDateError;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            0 => Ok(BadiMonth::AyyamIHa),
+            19 => Ok(BadiMonth::Ala),
+            1..=18 => Ok(MONTHS[number as usize - 1]),
+            _ => Err(BadiDateError::MonthInvalid(number)),
+        }
+    }
+}
+
+impl From<BadiMonth> for u8 {
+    fn from(month: BadiMonth) -> Self {
+        month.number()
+    }
+}
+
+/// Variant names in declaration (and calendar) order, as serialized
+const VARIANTS: [&str; 20] = [
+    "Baha",
+    "Jalal",
+    "Jamal",
+    "Azamat",
+    "Nur",
+    "Rahmat",
+    "Kalimat",
+    "Kamal",
+    "Asma",
+    "Izzat",
+    "Mashiyyat",
+    "Ilm",
+    "Qudrat",
+    "Qawl",
+    "Masail",
+    "Sharaf",
+    "Sultan",
+    "Mulk",
+    "AyyamIHa",
+    "Ala",
+];
+
+impl<'de> Deserialize<'de> for BadiMonth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BadiMonthVisitor)
+        } else {
+            deserializer.deserialize_enum("BadiMonth", &VARIANTS, BadiMonthVariantVisitor)
         }
     }
 }
 
-impl PartialOrd for BadiMonth {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+/// Deserializes the serialized (unit) variant of a [`BadiMonth`] in formats that are not self-describing
+struct BadiMonthVariantVisitor;
+
+impl<'de> Visitor<'de> for BadiMonthVariantVisitor {
+    type Value = BadiMonth;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a BadiMonth variant")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (month, variant) = data.variant::<BadiMonthVariant>()?;
+        variant.unit_variant()?;
+        Ok(month.0)
+    }
+}
+
+/// The identifier of a [`BadiMonth`] variant: its index or name
+struct BadiMonthVariant(BadiMonth);
+
+impl<'de> Deserialize<'de> for BadiMonthVariant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(BadiMonthVariantIdentifierVisitor)
+    }
+}
+
+struct BadiMonthVariantIdentifierVisitor;
+
+impl Visitor<'_> for BadiMonthVariantIdentifierVisitor {
+    type Value = BadiMonthVariant;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a BadiMonth variant index [0 - 19] or name")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        MONTHS
+            .get(v as usize)
+            .map(|month| BadiMonthVariant(*month))
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        VARIANTS
+            .iter()
+            .position(|name| *name == v)
+            .map(|index| BadiMonthVariant(MONTHS[index]))
+            .ok_or_else(|| E::unknown_variant(v, &VARIANTS))
+    }
+}
+
+struct BadiMonthVisitor;
+
+impl<'de> Visitor<'de> for BadiMonthVisitor {
+    type Value = BadiMonth;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Badi month name or number [0 - 19]")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        u8::try_from(v)
+            .ok()
+            .and_then(|number| BadiMonth::try_from(number).ok())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+            .and_then(|v| self.visit_u64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    /// The legacy `{"Month": 1}` form of `BadiMonth::Baha`
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let Some((key, number)) = map.next_entry::<String, u8>()? else {
+            return Err(de::Error::invalid_length(0, &self));
+        };
+        if key != "Month" {
+            return Err(de::Error::unknown_variant(&key, &["Month"]));
+        }
+        match number {
+            1..=19 => BadiMonth::try_from(number).map_err(de::Error::custom),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(number as u64),
+                &"a month number [1 - 19]",
+            )),
+        }
     }
 }
 
 impl BadiMonth {
     /// First month of the year (Bahá)
     pub fn first() -> Self {
-        BadiMonth::Month(1)
+        BadiMonth::Baha
     }
 
     /// Last month of the year (ʻAláʼ)
     pub fn last() -> Self {
-        BadiMonth::Month(19)
+        BadiMonth::Ala
     }
 
-    /// Next month of the year (None if `self` is ʻAláʼ)
-    pub fn next(&self) -> Option<Self> {
+    /// All 19 months and Ayyám-i-Há in calendar order (Bahá ... Mulk, Ayyám-i-Há, ʻAláʼ)
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        MONTHS.into_iter()
+    }
+
+    /// Month number [1 - 19] (0 for Ayyám-i-Há)
+    pub fn number(&self) -> u8 {
         match *self {
-            BadiMonth::Month(month) => match month.cmp(&18) {
-                std::cmp::Ordering::Less => Some(BadiMonth::Month(month + 1)),
-                std::cmp::Ordering::Equal => Some(BadiMonth::AyyamIHa),
-                std::cmp::Ordering::Greater => None,
-            },
-            BadiMonth::AyyamIHa => Some(BadiMonth::Month(19)),
+            BadiMonth::AyyamIHa => 0,
+            BadiMonth::Ala => 19,
+            month => month as u8 + 1,
         }
     }
 
+    /// Next month of the year (None if `self` is ʻAláʼ)
+    pub fn next(&self) -> Option<Self> {
+        MONTHS.get(*self as usize + 1).copied()
+    }
+
     /// Previous month of the year (None if `self` is Bahá)
     pub fn previous(&self) -> Option<Self> {
-        match *self {
-            BadiMonth::Month(month) => {
-                if month == 1 {
-                    None
-                } else if month == 19 {
-                    Some(BadiMonth::AyyamIHa)
-                } else if month < 19 {
-                    Some(BadiMonth::Month(month - 1))
-                } else {
-                    None
-                }
-            }
-            BadiMonth::AyyamIHa => Some(BadiMonth::Month(18)),
-        }
+        (*self as usize).checked_sub(1).map(|index| MONTHS[index])
     }
 
-    /// Return `self` if month is valid, otherwise [`BadiDateError::MonthInvalid`]
-    pub fn validate(&self) -> Result<Self, BadiDateError> {
-        if match *self {
-            BadiMonth::Month(month) => (1..=19).contains(&month),
-            BadiMonth::AyyamIHa => true,
-        } {
-            return Ok(*self);
-        }
-        Err(BadiDateError::MonthInvalid(*self))
+    /// Max number of days in the month (year required to compute Ayyám-i-Há days)
+    pub fn number_of_days(&self, year: u16) -> u16 {
+        self.number_of_days_with_rules(year, CalendarRules::default())
     }
 
-    /// Max number of days in the month (year required to compute Ayyám-i-Há days)
-    pub fn number_of_days(&self, year: u8) -> u16 {
+    /// Max number of days in the month under the given [`CalendarRules`]
+    pub fn number_of_days_with_rules(&self, year: u16, rules: CalendarRules) -> u16 {
         match *self {
-            BadiMonth::Month(_) => 19,
-            BadiMonth::AyyamIHa => get_number_of_ayyamiha_days(year),
+            BadiMonth::AyyamIHa => rules.number_of_ayyamiha_days(year),
+            _ => 19,
         }
     }
 
@@ -135,19 +307,19 @@ impl BadiMonth {
     /// extra: Additional meanings (English)
     pub fn name(&self, locale: &str) -> String {
         match self {
-            BadiMonth::Month(month) => {
-                let month_key = format!("month.{month}");
+            BadiMonth::AyyamIHa => t!("ayyamiha", locale = locale).to_string(),
+            month => {
+                let month_key = format!("month.{}", month.number());
                 t!(&month_key, locale = locale).to_string()
             }
-            BadiMonth::AyyamIHa => t!("ayyamiha", locale = locale).to_string(),
         }
     }
 
     /// Get a (debug) description of the month
     pub fn description(&self) -> String {
         match self {
-            BadiMonth::Month(_) => format!("the month of {}", self.name("tl")),
             BadiMonth::AyyamIHa => self.name("tl"),
+            _ => format!("the month of {}", self.name("tl")),
         }
     }
 }
@@ -165,8 +337,105 @@ mod tests {
             HashSet::<&str>::from_iter(rust_i18n::available_locales!())
         );
         assert_eq!("Bahá", BadiMonth::first().transliteration());
-        assert_eq!("كلمات", BadiMonth::Month(7).arabic());
-        assert_eq!("Words", BadiMonth::Month(7).english());
+        assert_eq!("كلمات", BadiMonth::Kalimat.arabic());
+        assert_eq!("Words", BadiMonth::Kalimat.english());
         assert_eq!("Ayyám-i-Há", BadiMonth::AyyamIHa.transliteration());
     }
+
+    #[test]
+    fn iter() {
+        let months: Vec<BadiMonth> = BadiMonth::iter().collect();
+        assert_eq!(months.len(), 20);
+        assert_eq!(BadiMonth::iter().len(), 20);
+        assert_eq!(months[0], BadiMonth::first());
+        assert_eq!(months[18], BadiMonth::AyyamIHa);
+        assert_eq!(BadiMonth::iter().next_back(), Some(BadiMonth::last()));
+        assert!(months.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(months
+            .windows(2)
+            .all(|pair| pair[0].next() == Some(pair[1]) && pair[1].previous() == Some(pair[0])));
+    }
+
+    #[test]
+    fn numbers() {
+        for (index, month) in BadiMonth::iter().enumerate() {
+            assert_eq!(BadiMonth::try_from(month.number()).unwrap(), month);
+            assert_eq!(u8::from(month), month.number());
+            match index {
+                18 => assert_eq!(month.number(), 0),
+                19 => assert_eq!(month.number(), 19),
+                _ => assert_eq!(month.number(), index as u8 + 1),
+            }
+        }
+        assert_eq!(BadiMonth::try_from(1).unwrap(), BadiMonth::Baha);
+        assert_eq!(BadiMonth::try_from(18).unwrap(), BadiMonth::Mulk);
+        assert_eq!(BadiMonth::try_from(19).unwrap(), BadiMonth::Ala);
+        assert!(matches!(
+            BadiMonth::try_from(20),
+            Err(BadiDateError::MonthInvalid(20))
+        ));
+        assert!(BadiMonth::Mulk < BadiMonth::AyyamIHa);
+        assert!(BadiMonth::AyyamIHa < BadiMonth::Ala);
+        assert_eq!(BadiMonth::Baha.previous(), None);
+        assert_eq!(BadiMonth::Ala.next(), None);
+    }
+
+    #[test]
+    fn serde() {
+        for month in BadiMonth::iter() {
+            let json = serde_json::to_string(&month).unwrap();
+            assert_eq!(serde_json::from_str::<BadiMonth>(&json).unwrap(), month);
+            let number = month.number().to_string();
+            assert_eq!(serde_json::from_str::<BadiMonth>(&number).unwrap(), month);
+            let name = serde_json::to_string(&month.transliteration()).unwrap();
+            assert_eq!(serde_json::from_str::<BadiMonth>(&name).unwrap(), month);
+        }
+        assert_eq!(
+            serde_json::to_string(&BadiMonth::Baha).unwrap(),
+            r#""Baha""#
+        );
+        assert_eq!(
+            serde_json::to_string(&BadiMonth::AyyamIHa).unwrap(),
+            r#""AyyamIHa""#
+        );
+        // legacy `BadiMonth::Month(u8)` form
+        assert_eq!(
+            serde_json::from_str::<BadiMonth>(r#"{"Month":7}"#).unwrap(),
+            BadiMonth::Kalimat
+        );
+        for json in [
+            "20",
+            "-1",
+            r#""Dec""#,
+            r#"{"Month":0}"#,
+            r#"{"Month":20}"#,
+            r#"{"Day":1}"#,
+            "{}",
+            "null",
+        ] {
+            assert!(serde_json::from_str::<BadiMonth>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn serde_not_self_describing() {
+        for month in BadiMonth::iter() {
+            let bytes = bincode::serialize(&month).unwrap();
+            assert_eq!(bincode::deserialize::<BadiMonth>(&bytes).unwrap(), month);
+        }
+        let denver: chrono_tz::Tz = "America/Denver".parse().unwrap();
+        let date = crate::BadiDate::new(181, BadiMonth::AyyamIHa, 4).unwrap();
+        let bytes = bincode::serialize(&date).unwrap();
+        assert_eq!(
+            bincode::deserialize::<crate::BadiDate>(&bytes).unwrap(),
+            date
+        );
+        let local = crate::LocalBadiDate::new(181, BadiMonth::Ala, 19, denver, None).unwrap();
+        let bytes = bincode::serialize(&local).unwrap();
+        assert_eq!(
+            bincode::deserialize::<crate::LocalBadiDate>(&bytes).unwrap(),
+            local
+        );
+        assert!(bincode::deserialize::<BadiMonth>(&bincode::serialize(&20u32).unwrap()).is_err());
+    }
 }
@@ -0,0 +1,154 @@
+use chrono::Weekday;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+
+/// One of the 7 days of the Badí‘ week, which begins on Saturday
+/// (the Badi day starts at sunset, so e.g. [`BadiWeekday::Jalal`] starts at sunset on Friday)
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum BadiWeekday {
+    /// Jalál (Glory) -- Saturday
+    Jalal,
+    /// Jamál (Beauty) -- Sunday
+    Jamal,
+    /// Kamál (Perfection) -- Monday
+    Kamal,
+    /// Fiḍál (Grace) -- Tuesday
+    Fidal,
+    /// ʻIdál (Justice) -- Wednesday
+    Idal,
+    /// Istijlál (Majesty) -- Thursday
+    Istijlal,
+    /// Istiqlál (Independence) -- Friday
+    Istiqlal,
+}
+
+impl BadiWeekday {
+    /// 1-based number of the day in the Badí‘ week (Jalál = 1, Istiqlál = 7)
+    pub fn number_from_jalal(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// Next day of the week (wraps from Istiqlál to Jalál)
+    pub fn succ(&self) -> Self {
+        Weekday::from(*self).succ().into()
+    }
+
+    /// Previous day of the week (wraps from Jalál to Istiqlál)
+    pub fn pred(&self) -> Self {
+        Weekday::from(*self).pred().into()
+    }
+
+    /// Arabic name of the weekday in the Arabic charater set
+    pub fn arabic(&self) -> String {
+        self.name("ar")
+    }
+
+    /// English name of the weekday (as opposed to transliteration)
+    pub fn english(&self) -> String {
+        self.name("en")
+    }
+
+    /// English transliteration of Arabic name of the weekday
+    pub fn transliteration(&self) -> String {
+        self.name("tl")
+    }
+
+    /// Get name of the weekday in a locale (see /locales/app.yaml):
+    /// en: English
+    /// ar: Arabic
+    /// tl: English transliteration
+    /// extra: Additional meanings (English)
+    pub fn name(&self, locale: &str) -> String {
+        let weekday_key = format!("weekday.{}", self.number_from_jalal());
+        t!(&weekday_key, locale = locale).to_string()
+    }
+}
+
+impl From<Weekday> for BadiWeekday {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Sat => BadiWeekday::Jalal,
+            Weekday::Sun => BadiWeekday::Jamal,
+            Weekday::Mon => BadiWeekday::Kamal,
+            Weekday::Tue => BadiWeekday::Fidal,
+            Weekday::Wed => BadiWeekday::Idal,
+            Weekday::Thu => BadiWeekday::Istijlal,
+            Weekday::Fri => BadiWeekday::Istiqlal,
+        }
+    }
+}
+
+impl From<BadiWeekday> for Weekday {
+    fn from(weekday: BadiWeekday) -> Self {
+        match weekday {
+            BadiWeekday::Jalal => Weekday::Sat,
+            BadiWeekday::Jamal => Weekday::Sun,
+            BadiWeekday::Kamal => Weekday::Mon,
+            BadiWeekday::Fidal => Weekday::Tue,
+            BadiWeekday::Idal => Weekday::Wed,
+            BadiWeekday::Istijlal => Weekday::Thu,
+            BadiWeekday::Istiqlal => Weekday::Fri,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, TimeZone, Weekday};
+    use chrono_tz::Tz;
+
+    use crate::{
+        BadiDate, BadiDateLike, BadiDateOps, BadiMonth, BadiWeekday, CalendarRules, Coordinates,
+        FromDateTime, LocalBadiDate, ToDateTime,
+    };
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(BadiWeekday::from(Weekday::Sat), BadiWeekday::Jalal);
+        assert_eq!(Weekday::from(BadiWeekday::Istiqlal), Weekday::Fri);
+        let mut weekday = BadiWeekday::Jalal;
+        for number in 1..=7 {
+            assert_eq!(weekday.number_from_jalal(), number);
+            assert_eq!(BadiWeekday::from(Weekday::from(weekday)), weekday);
+            assert_eq!(weekday.succ().pred(), weekday);
+            weekday = weekday.succ();
+        }
+        assert_eq!(weekday, BadiWeekday::Jalal);
+        assert_eq!(BadiWeekday::Jalal.pred(), BadiWeekday::Istiqlal);
+        assert_eq!(BadiWeekday::Fidal.transliteration(), "Fiḍál");
+        assert_eq!(BadiWeekday::Istiqlal.english(), "Independence");
+        assert_eq!(BadiWeekday::Jamal.arabic(), "جمال");
+
+        // Naw-Rúz 181 B.E. is Wednesday 20 March 2024
+        let naw_ruz = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        assert_eq!(naw_ruz.weekday(), BadiWeekday::Idal);
+        // 4 Ayyám-i-Há 180 B.E. is Thursday 29 February 2024
+        let ayyamiha = BadiDate::new(180, BadiMonth::AyyamIHa, 4).unwrap();
+        assert_eq!(ayyamiha.weekday(), BadiWeekday::Istijlal);
+        // Naw-Rúz 181 B.E. is Thursday 21 March 2024 under the Western calendar
+        let western = BadiDate::new_with_rules(
+            181,
+            BadiMonth::Baha,
+            1,
+            CalendarRules::WesternGregorianPinned,
+        )
+        .unwrap();
+        assert_eq!(western.weekday(), BadiWeekday::Istijlal);
+        assert_eq!(western.next_day().weekday(), BadiWeekday::Istiqlal);
+    }
+
+    #[test]
+    fn local_weekday_starts_at_sunset() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        // Friday 7 June 2024: before sunset it is still Istiqlál, after sunset it is Jalál
+        let afternoon = denver.with_ymd_and_hms(2024, 6, 7, 15, 0, 0).unwrap();
+        let evening = denver.with_ymd_and_hms(2024, 6, 7, 21, 0, 0).unwrap();
+        let before = LocalBadiDate::from_datetime(afternoon, coords).unwrap();
+        let after = LocalBadiDate::from_datetime(evening, coords).unwrap();
+        assert_eq!(before.weekday(), BadiWeekday::Istiqlal);
+        assert_eq!(after.weekday(), BadiWeekday::Jalal);
+        assert_eq!(after.start().weekday(), Weekday::Fri);
+        assert_eq!(after.end().weekday(), Weekday::Sat);
+    }
+}
@@ -0,0 +1,171 @@
+use chrono::{Datelike, Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{astronomy::*, statics::*, BadiDateError, BadiWeekday, BahaiHolyDay};
+
+/// Rules determining Naw-Rúz, the number of days of Ayyám-i-Há, and the holy days of a Badí‘ year
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum CalendarRules {
+    /// The unified Badí‘ calendar observed worldwide since Naw-Rúz 172 B.E. (2015):
+    /// Naw-Rúz falls on the day of the March equinox in Ṭihrán and the Twin Holy Days follow
+    /// the eighth new moon after Naw-Rúz; applied proleptically to years before 172 B.E., so that e.g. Naw-Rúz 1 B.E.
+    /// is 20 March 1844 rather than the historically observed 21 March (see
+    /// [`CalendarRules::WesternGregorianPinned`])
+    #[default]
+    Unified2015,
+    /// The calendar observed in the West before 172 B.E.: Naw-Rúz pinned to 21 March,
+    /// 5 days of Ayyám-i-Há before Gregorian leap days, and holy days on fixed Gregorian dates
+    WesternGregorianPinned,
+}
+
+impl CalendarRules {
+    /// Gregorian date of Naw-Rúz of the given Badi (B.E.) year
+    /// (the sunset starting Naw-Rúz occurs on the previous Gregorian day)
+    pub(crate) fn naw_ruz_date(&self, year: u16) -> NaiveDate {
+        let greg_year = YEAR_ZERO_IN_GREGORIAN + year as i32;
+        match self {
+            CalendarRules::Unified2015 => naw_ruz_date(greg_year),
+            CalendarRules::WesternGregorianPinned => {
+                NaiveDate::from_ymd_opt(greg_year, 3, 21).unwrap()
+            }
+        }
+    }
+
+    /// Gregorian date of the given 1-based `day_of_year` of the given Badi (B.E.) year
+    /// (the date of its daylight hours; the Badi day starts at sunset on the previous Gregorian day)
+    pub(crate) fn gregorian_date(&self, year: u16, day_of_year: u16) -> NaiveDate {
+        self.naw_ruz_date(year) + Days::new(day_of_year as u64 - 1)
+    }
+
+    /// Weekday of the given 1-based `day_of_year` of the given Badi (B.E.) year
+    /// (that of the Gregorian date on which the Badi day ends)
+    pub(crate) fn weekday(&self, year: u16, day_of_year: u16) -> BadiWeekday {
+        self.gregorian_date(year, day_of_year).weekday().into()
+    }
+
+    /// Badi (B.E.) year and 1-based day of the year of the Badi day ending at sunset on the given Gregorian date
+    pub(crate) fn year_and_day_of_year(
+        &self,
+        date: NaiveDate,
+    ) -> Result<(u16, u16), BadiDateError> {
+        let mut year = date.year() - YEAR_ZERO_IN_GREGORIAN;
+        if year < 1 {
+            return Err(BadiDateError::DateNotSupported);
+        }
+        if date < self.naw_ruz_date(year as u16) {
+            year -= 1;
+        }
+        if !(1..=LAST_YEAR_SUPPORTED as i32).contains(&year) {
+            return Err(BadiDateError::DateNotSupported);
+        }
+        let year = year as u16;
+        let day_of_year = (date - self.naw_ruz_date(year)).num_days() as u16 + 1;
+        Ok((year, day_of_year))
+    }
+
+    /// Number of days in Ayyám-i-Há of the given Badi (B.E.) year
+    pub(crate) fn number_of_ayyamiha_days(&self, year: u16) -> u16 {
+        let days_in_year =
+            (self.naw_ruz_date(year + 1) - self.naw_ruz_date(year)).num_days() as u16;
+        // the days not in the 18 months before Ayyám-i-Há or in the 19th month after it
+        days_in_year - AYYAMIHA_DAY_0 - 19
+    }
+
+    /// 1-based day of the given Badi (B.E.) year on which `holy_day` occurs
+    pub(crate) fn holy_day_of_year(&self, holy_day: BahaiHolyDay, year: u16) -> u16 {
+        match (self, holy_day) {
+            (CalendarRules::Unified2015, BahaiHolyDay::BirthOfTheBab) => {
+                self.birth_of_the_bab(year)
+            }
+            (CalendarRules::Unified2015, BahaiHolyDay::BirthOfBahaullah) => {
+                self.birth_of_the_bab(year) + 1
+            }
+            // 23 May and 9 July (8 ʻAẓamat and 17 Raḥmat are 24 May and 10 July)
+            (CalendarRules::WesternGregorianPinned, BahaiHolyDay::DeclarationOfTheBab) => 64,
+            (CalendarRules::WesternGregorianPinned, BahaiHolyDay::MartyrdomOfTheBab) => 111,
+            _ => *HOLY_DAYS_FALLBACK.get(&holy_day).unwrap(),
+        }
+    }
+
+    /// 1-based day of the given Badi (B.E.) year on which the Birth of the Báb occurs
+    /// (the day after the eighth new moon after Naw-Rúz, as seen from Ṭihrán)
+    fn birth_of_the_bab(&self, year: u16) -> u16 {
+        let greg_year = YEAR_ZERO_IN_GREGORIAN + year as i32;
+        1 + (birth_of_the_bab_date(greg_year) - self.naw_ruz_date(year)).num_days() as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    use crate::{
+        BadiDate, BadiMonth, BahaiHolyDay, CalendarRules, Coordinates, FromDateTime,
+        HolyDayProviding, LocalBadiDate, ToDateTime,
+    };
+
+    #[test]
+    fn western_gregorian_pinned() {
+        let rules = CalendarRules::WesternGregorianPinned;
+        // 180 B.E. ends in March 2024 (a Gregorian leap year)
+        assert_eq!(BadiMonth::AyyamIHa.number_of_days_with_rules(180, rules), 5);
+        assert_eq!(BadiMonth::AyyamIHa.number_of_days(180), 4);
+        assert!(BadiDate::new_with_rules(180, BadiMonth::AyyamIHa, 5, rules).is_ok());
+        assert!(BadiDate::new(180, BadiMonth::AyyamIHa, 5).is_err());
+
+        // 20 October / 12 November
+        assert_eq!(
+            BahaiHolyDay::BirthOfTheBab.day_of_year_with_rules(190, rules),
+            214
+        );
+        assert_eq!(
+            BahaiHolyDay::BirthOfBahaullah.day_of_year_with_rules(190, rules),
+            237
+        );
+        // 23 May
+        let declaration = BadiDate::new_with_rules(120, BadiMonth::Azamat, 7, rules).unwrap();
+        assert_eq!(
+            declaration.holy_day(),
+            Some(BahaiHolyDay::DeclarationOfTheBab)
+        );
+        assert_eq!(
+            BadiDate::new(120, BadiMonth::Azamat, 8).unwrap().holy_day(),
+            Some(BahaiHolyDay::DeclarationOfTheBab)
+        );
+    }
+
+    #[test]
+    fn from_datetime_with_rules() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        // Naw-Rúz 181 B.E. is 20 March 2024 under the unified calendar, 21 March under the Western calendar
+        let date = denver.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+        let unified = LocalBadiDate::from_datetime(date, coords).unwrap();
+        assert_eq!(
+            unified,
+            LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, coords).unwrap()
+        );
+        let rules = CalendarRules::WesternGregorianPinned;
+        let western = LocalBadiDate::from_datetime_with_rules(date, coords, rules).unwrap();
+        assert_eq!(
+            western,
+            LocalBadiDate::new_with_rules(180, BadiMonth::Ala, 19, denver, coords, rules).unwrap()
+        );
+        assert_ne!(unified, western);
+        for badi_date in [unified, western] {
+            assert!(badi_date.start() <= date && date <= badi_date.end());
+        }
+
+        // a Feast recorded in 1950 under the rules of the time
+        let date = denver.with_ymd_and_hms(1950, 4, 9, 12, 0, 0).unwrap();
+        let western = LocalBadiDate::from_datetime_with_rules(date, coords, rules).unwrap();
+        assert_eq!(
+            western,
+            LocalBadiDate::new_with_rules(107, BadiMonth::Jalal, 1, denver, coords, rules).unwrap()
+        );
+        assert!(western.start() <= date && date <= western.end());
+    }
+}
@@ -1,28 +1,368 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+
+use crate::astronomy::*;
 
 /// WGS-84 GPS coordinates used to calculate sunset times for a [`LocalBadiDate`][`crate::LocalBadiDate`]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+/// (deserialization is validated like [`Coordinates::new`])
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "RawCoordinates")]
 pub struct Coordinates {
     /// The latitude [-90...90.] in the WGS-84 coordinate system
     pub latitude: f64,
     /// The longitude [-180...180.] in the WGS-84 coordinate system
     pub longitude: f64,
+    /// The elevation in metres [-500...9000.] above the horizon the sun sets behind (usually sea level);
+    /// a higher observer sees a lower horizon (its dip) and so a later sunset
+    #[serde(default)]
+    pub elevation: Option<f64>,
+}
+
+/// Human-readable formats omit a missing elevation; other formats (e.g. bincode) need every field
+impl Serialize for Coordinates {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let skip_elevation = self.elevation.is_none() && serializer.is_human_readable();
+        let mut state =
+            serializer.serialize_struct("Coordinates", if skip_elevation { 2 } else { 3 })?;
+        state.serialize_field("latitude", &self.latitude)?;
+        state.serialize_field("longitude", &self.longitude)?;
+        if skip_elevation {
+            state.skip_field("elevation")?;
+        } else {
+            state.serialize_field("elevation", &self.elevation)?;
+        }
+        state.end()
+    }
+}
+
+/// Unvalidated fields of [`Coordinates`] as deserialized
+#[derive(Deserialize)]
+struct RawCoordinates {
+    latitude: f64,
+    longitude: f64,
+    #[serde(default)]
+    elevation: Option<f64>,
+}
+
+impl TryFrom<RawCoordinates> for Coordinates {
+    type Error = CoordinatesError;
+
+    fn try_from(raw: RawCoordinates) -> Result<Self, Self::Error> {
+        let coordinates = Self::new(raw.latitude, raw.longitude)?;
+        match raw.elevation {
+            Some(elevation) => coordinates.with_elevation(elevation),
+            None => Ok(coordinates),
+        }
+    }
 }
 
 /// Error returned for invalid [`Coordinates`]
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum CoordinatesError {
-    /// The latitude is not within -90...90. inclusive
+    /// The latitude is not within -90...90. inclusive (or is not a finite number)
     LatitudeInvalid,
-    /// The longitude is not within -180...180. inclusive
+    /// The longitude is not within -180...180. inclusive (or is not a finite number)
     LongitudeInvalid,
+    /// The elevation is not within -500...9000. metres inclusive (or is not a finite number)
+    ElevationInvalid,
+    /// The string is not in a supported format (see [`Coordinates::from_str`][`std::str::FromStr::from_str`])
+    FormatInvalid,
 }
 
+/// Decimal "latitude,longitude" (e.g. "39.613319,-105.016647") with as many digits as needed to parse back exactly,
+/// followed by ",elevation" in metres if there is one (e.g. "39.613319,-105.016647,1609")
 impl fmt::Display for Coordinates {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:0>11.6},{:0>11.6}", self.longitude, self.latitude)
+        write!(f, "{},{}", self.latitude, self.longitude)?;
+        if let Some(elevation) = self.elevation {
+            write!(f, ",{}", elevation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses one ISO 6709 coordinate: a sign followed by degrees (`deg_digits` integer digits), degrees and minutes,
+/// or degrees, minutes, and seconds, where only the last part may have a fraction (e.g. "+39.6133", "-10501.0", "+393648")
+fn parse_iso6709_part(part: &str, deg_digits: usize) -> Option<f64> {
+    let (sign, number) = match (part.get(..1)?, &part[1..]) {
+        ("+", number) => (1., number),
+        ("-", number) => (-1., number),
+        _ => return None,
+    };
+    if !number.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    let integer_digits = number.find('.').unwrap_or(number.len());
+    let (degrees, minutes, seconds) = match integer_digits.checked_sub(deg_digits)? {
+        0 => (number.parse::<f64>().ok()?, 0., 0.),
+        2 => (
+            number[..deg_digits].parse::<f64>().ok()?,
+            number[deg_digits..].parse::<f64>().ok()?,
+            0.,
+        ),
+        4 => (
+            number[..deg_digits].parse::<f64>().ok()?,
+            number[deg_digits..deg_digits + 2].parse::<f64>().ok()?,
+            number[deg_digits + 2..].parse::<f64>().ok()?,
+        ),
+        _ => return None,
+    };
+    if minutes >= 60. || seconds >= 60. {
+        return None;
+    }
+    Some(sign * (degrees + minutes / 60. + seconds / 3600.))
+}
+
+/// Parses an ISO 6709 point (e.g. "+39.6133-105.0166/", "+393648-1050100+1609/") into latitude, longitude,
+/// and altitude (metres) if any; a CRS is ignored
+fn parse_iso6709(s: &str) -> Option<(f64, f64, Option<f64>)> {
+    let s = s.strip_suffix('/').unwrap_or(s);
+    let s = s.split_once("CRS").map_or(s, |(point, _)| point);
+    let mut parts = Vec::new();
+    for (index, c) in s.char_indices() {
+        if c == '+' || c == '-' {
+            parts.push(index);
+        }
+    }
+    if !(2..=3).contains(&parts.len()) || parts[0] != 0 {
+        return None;
+    }
+    parts.push(s.len());
+    let latitude = parse_iso6709_part(&s[parts[0]..parts[1]], 2)?;
+    let longitude = parse_iso6709_part(&s[parts[1]..parts[2]], 3)?;
+    let altitude = match parts.get(3) {
+        Some(&end) => {
+            let altitude = &s[parts[2]..end];
+            if !altitude[1..]
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.')
+            {
+                return None;
+            }
+            Some(altitude.parse().ok()?)
+        }
+        None => None,
+    };
+    Some((latitude, longitude, altitude))
+}
+
+/// Latitude or longitude being parsed from decimal degrees or degrees/minutes/seconds
+#[derive(Default)]
+struct DegreesPart {
+    /// Values with their unit (0 = degrees, 1 = minutes, 2 = seconds) if it has a symbol
+    values: Vec<(f64, Option<usize>)>,
+    /// Sign before the degrees (`Some(true)` if negative)
+    sign: Option<bool>,
+    /// N, S, E, or W before or after the degrees
+    hemisphere: Option<char>,
+}
+
+impl DegreesPart {
+    fn with_hemisphere(hemisphere: char) -> Self {
+        Self {
+            hemisphere: Some(hemisphere),
+            ..Default::default()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.sign.is_none() && self.hemisphere.is_none()
+    }
+
+    /// Whether the part is a latitude (N or S) or a longitude (E or W), if it has a hemisphere
+    fn is_latitude(&self) -> Option<bool> {
+        self.hemisphere
+            .map(|hemisphere| matches!(hemisphere, 'N' | 'S'))
+    }
+
+    fn value(&self) -> Option<f64> {
+        if self.values.is_empty() || (self.sign.is_some() && self.hemisphere.is_some()) {
+            return None;
+        }
+        // units without symbols follow the previous unit (degrees, then minutes, then seconds)
+        let mut parts = [0.; 3];
+        let mut next_unit = 0;
+        for &(value, unit) in &self.values {
+            let unit = unit.unwrap_or(next_unit);
+            if unit < next_unit || unit > 2 {
+                return None;
+            }
+            parts[unit] = value;
+            next_unit = unit + 1;
+        }
+        let [degrees, minutes, seconds] = parts;
+        if minutes >= 60. || seconds >= 60. {
+            return None;
+        }
+        let value = degrees + minutes / 60. + seconds / 3600.;
+        let negative = self.sign == Some(true) || matches!(self.hemisphere, Some('S' | 'W'));
+        Some(if negative { -value } else { value })
+    }
+}
+
+/// Parses signed decimal numbers separated only by whitespace (e.g. "39.61 -105.01 1609"), if that is all there is
+fn parse_plain_numbers(s: &str) -> Option<Vec<f64>> {
+    s.split_whitespace()
+        .map(|number| {
+            let digits = number.strip_prefix(['+', '-', '−']).unwrap_or(number);
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                return None;
+            }
+            number.replace('−', "-").parse().ok()
+        })
+        .collect()
+}
+
+/// Parses decimal degrees or degrees/minutes/seconds with optional hemispheres
+/// (e.g. "39.61, -105.01", "39°36'48\"N 105°01'00\"W", "N 39 36.8 W 105 1"), optionally followed by an elevation
+/// in metres (e.g. "39.61, -105.01, 1609")
+fn parse_degrees(s: &str) -> Option<(f64, f64, Option<f64>)> {
+    if let Some(numbers) = parse_plain_numbers(s) {
+        return match numbers[..] {
+            [latitude, longitude] => Some((latitude, longitude, None)),
+            [latitude, longitude, elevation] => Some((latitude, longitude, Some(elevation))),
+            _ => None,
+        };
+    }
+    let mut parts = vec![DegreesPart::default()];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let part = parts.last_mut()?;
+        match c {
+            '+' | '-' | '−' => {
+                // a sign starts the next part
+                if !part.is_empty() {
+                    parts.push(DegreesPart::default());
+                }
+                parts.last_mut()?.sign = Some(c != '+');
+            }
+            '0'..='9' | '.' => {
+                let mut number = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+                    number.push(c);
+                }
+                part.values.push((number.parse().ok()?, None));
+            }
+            '°' | 'º' | '\'' | '′' | '’' | '"' | '″' | '”' => {
+                let unit = match c {
+                    '°' | 'º' => 0,
+                    '"' | '″' | '”' => 2,
+                    // two primes are seconds
+                    _ if chars.next_if(|c| matches!(c, '\'' | '′' | '’')).is_some() => 2,
+                    _ => 1,
+                };
+                let (_, last_unit) = part.values.last_mut()?;
+                if last_unit.replace(unit).is_some() {
+                    return None;
+                }
+                // degrees after other values (without a separator) start the next part
+                if unit == 0 && part.values.len() > 1 {
+                    let degrees = part.values.pop()?;
+                    parts.push(DegreesPart {
+                        values: vec![degrees],
+                        ..Default::default()
+                    });
+                }
+            }
+            'N' | 'S' | 'E' | 'W' | 'n' | 's' | 'e' | 'w' => {
+                let hemisphere = c.to_ascii_uppercase();
+                if part.hemisphere.is_some() {
+                    parts.push(DegreesPart::with_hemisphere(hemisphere));
+                } else {
+                    part.hemisphere = Some(hemisphere);
+                    // a hemisphere after the values ends the part
+                    if !part.values.is_empty() {
+                        parts.push(DegreesPart::default());
+                    }
+                }
+            }
+            ',' | ';' => {
+                // a hemisphere after the values may already have ended the part
+                if !part.is_empty() {
+                    parts.push(DegreesPart::default());
+                } else if parts.len() == 1 {
+                    return None;
+                }
+            }
+            c if c.is_whitespace() => {}
+            _ => return None,
+        }
+    }
+    parts.retain(|part| !part.is_empty());
+    // a third part holding a single number without a unit or hemisphere is the elevation
+    let elevation = match &parts[..] {
+        [_, _, elevation]
+            if elevation.hemisphere.is_none()
+                && elevation.values.len() == 1
+                && elevation.values[0].1.is_none() =>
+        {
+            let (value, _) = elevation.values[0];
+            Some(if elevation.sign == Some(true) {
+                -value
+            } else {
+                value
+            })
+        }
+        _ => None,
+    };
+    if elevation.is_some() {
+        parts.pop();
+    }
+    match &parts[..] {
+        [first, second] => {
+            let (latitude, longitude) = match (first.is_latitude(), second.is_latitude()) {
+                (Some(first), Some(second)) if first == second => return None,
+                (Some(false), _) | (_, Some(true)) => (second, first),
+                _ => (first, second),
+            };
+            Some((latitude.value()?, longitude.value()?, elevation))
+        }
+        _ => None,
+    }
+}
+
+/// Accepts decimal degrees ("39.613319,-105.016647", "39.61 -105.01", "39.61° N, 105.01° W"),
+/// degrees/minutes/seconds ("39°36'48\"N 105°01'00\"W", "39°36.8'N, 105°1'W"), or an ISO 6709 point
+/// ("+39.6133-105.0166/", "+393648-1050100/"); latitude comes first unless hemispheres say otherwise,
+/// and an elevation in metres may follow, separated like the latitude and longitude
+/// ("39.613319,-105.016647,1609", "39.61 -105.01 1609", "+393648-1050100+1609/")
+impl FromStr for Coordinates {
+    type Err = CoordinatesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let iso6709 =
+            s.starts_with(['+', '-']) && !s.contains(|c: char| c.is_whitespace() || c == ',');
+        let (latitude, longitude, elevation) = if s.ends_with('/') {
+            parse_iso6709(s)
+        } else if iso6709 {
+            parse_iso6709(s).or_else(|| parse_degrees(s))
+        } else {
+            parse_degrees(s)
+        }
+        .ok_or(CoordinatesError::FormatInvalid)?;
+        let coordinates = Coordinates::new(latitude, longitude)?;
+        match elevation {
+            Some(elevation) => coordinates.with_elevation(elevation),
+            None => Ok(coordinates),
+        }
+    }
+}
+
+/// [`Coordinates`] displayed as an ISO 6709 point in decimal degrees (e.g. "+39.613319-105.016647/"),
+/// with the elevation as its altitude if there is one (e.g. "+39.613319-105.016647+1609/"); see [`Coordinates::iso6709`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Iso6709Coordinates(pub Coordinates);
+
+impl fmt::Display for Iso6709Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+010.6}{:+011.6}", self.0.latitude, self.0.longitude)?;
+        if let Some(elevation) = self.0.elevation {
+            write!(f, "{:+}", elevation)?;
+        }
+        write!(f, "/")
     }
 }
 
@@ -31,22 +371,395 @@ impl CoordinatesError {
     pub fn message(&self) -> String {
         match self {
             CoordinatesError::LatitudeInvalid => {
-                "Latitude must be between -90.0 and 90.0 (inclusive)".to_string()
+                "Latitude must be a number between -90.0 and 90.0 (inclusive)".to_string()
             }
             CoordinatesError::LongitudeInvalid => {
-                "Longitude must be between -180.0 and 180.0 (inclusive)".to_string()
+                "Longitude must be a number between -180.0 and 180.0 (inclusive)".to_string()
+            }
+            CoordinatesError::ElevationInvalid => {
+                "Elevation must be a number of metres between -500.0 and 9000.0 (inclusive)"
+                    .to_string()
+            }
+            CoordinatesError::FormatInvalid => {
+                "Coordinates must be decimal degrees, degrees/minutes/seconds, or ISO 6709"
+                    .to_string()
             }
         }
     }
 }
 
+impl fmt::Display for CoordinatesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CoordinatesError: {}", self.message())
+    }
+}
+
+impl std::error::Error for CoordinatesError {}
+
 impl Coordinates {
     /// Create a new set of WGS-84 GPS coordinates used in calculating sunset times
-    /// (ensure these match the time zone being used!)
+    /// (ensure these match the time zone being used!); checks that both are finite and in range
+    /// (the elevation is `None`: sunset is calculated at sea level; see [`Coordinates::with_elevation`])
     pub fn new(latitude: f64, longitude: f64) -> Result<Self, CoordinatesError> {
+        // NaN is never contained in a range
+        if !(-90. ..=90.).contains(&latitude) {
+            return Err(CoordinatesError::LatitudeInvalid);
+        }
+        if !(-180. ..=180.).contains(&longitude) {
+            return Err(CoordinatesError::LongitudeInvalid);
+        }
         Ok(Self {
             latitude,
             longitude,
+            elevation: None,
+        })
+    }
+
+    /// Copy with the given elevation in metres (e.g. 1609. for Denver) so that sunset accounts for the dip of the horizon;
+    /// checks that it is finite and in range
+    pub fn with_elevation(&self, elevation: f64) -> Result<Self, CoordinatesError> {
+        if !(-500. ..=9000.).contains(&elevation) {
+            return Err(CoordinatesError::ElevationInvalid);
+        }
+        Ok(Self {
+            elevation: Some(elevation),
+            ..*self
         })
     }
+
+    /// Computes the moment of the given [`SolarEvent`] on the given Gregorian date (in the solar day around local
+    /// noon) with the default [`HorizonCriteria`]; `None` if it does not occur on that date
+    /// (e.g. no sunset in the midnight sun)
+    pub fn solar_event(&self, event: SolarEvent, date: NaiveDate) -> Option<DateTime<Utc>> {
+        self.solar_event_with_criteria(event, date, HorizonCriteria::default())
+    }
+
+    /// Computes the moment of the given [`SolarEvent`] on the given Gregorian date with the given
+    /// [`HorizonCriteria`] for sunrise and sunset (e.g. the center of the sun on the geometric horizon)
+    pub fn solar_event_with_criteria(
+        &self,
+        event: SolarEvent,
+        date: NaiveDate,
+        criteria: HorizonCriteria,
+    ) -> Option<DateTime<Utc>> {
+        solar_event(
+            self.latitude,
+            self.longitude,
+            self.elevation.unwrap_or(0.),
+            event,
+            criteria,
+            date,
+        )
+    }
+
+    /// Computes the moment of solar noon (the sun crosses the meridian) on the given Gregorian date
+    pub fn solar_noon(&self, date: NaiveDate) -> DateTime<Utc> {
+        solar_noon(self.longitude, date)
+    }
+
+    /// Display as an ISO 6709 point in decimal degrees (e.g. "+39.613319-105.016647/")
+    pub fn iso6709(&self) -> Iso6709Coordinates {
+        Iso6709Coordinates(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        for (latitude, longitude, expected) in [
+            (39.613319, -105.016647, Ok(())),
+            (0., 0., Ok(())),
+            (90., 180., Ok(())),
+            (-90., -180., Ok(())),
+            (-0., -0., Ok(())),
+            (90.000001, 0., Err(CoordinatesError::LatitudeInvalid)),
+            (-90.000001, 0., Err(CoordinatesError::LatitudeInvalid)),
+            (500., 0., Err(CoordinatesError::LatitudeInvalid)),
+            (f64::NAN, 0., Err(CoordinatesError::LatitudeInvalid)),
+            (f64::INFINITY, 0., Err(CoordinatesError::LatitudeInvalid)),
+            (
+                f64::NEG_INFINITY,
+                0.,
+                Err(CoordinatesError::LatitudeInvalid),
+            ),
+            (0., 180.000001, Err(CoordinatesError::LongitudeInvalid)),
+            (0., -180.000001, Err(CoordinatesError::LongitudeInvalid)),
+            (0., 360., Err(CoordinatesError::LongitudeInvalid)),
+            (0., f64::NAN, Err(CoordinatesError::LongitudeInvalid)),
+            (0., f64::INFINITY, Err(CoordinatesError::LongitudeInvalid)),
+            (
+                0.,
+                f64::NEG_INFINITY,
+                Err(CoordinatesError::LongitudeInvalid),
+            ),
+            (f64::NAN, f64::NAN, Err(CoordinatesError::LatitudeInvalid)),
+        ] {
+            let coordinates = Coordinates::new(latitude, longitude);
+            assert_eq!(coordinates.map(|_| ()), expected, "{latitude}, {longitude}");
+            if let Ok(coordinates) = coordinates {
+                assert_eq!(coordinates.latitude, latitude);
+                assert_eq!(coordinates.longitude, longitude);
+                assert_eq!(coordinates.elevation, None);
+            }
+        }
+        let denver = Coordinates::new(39.613319, -105.016647).unwrap();
+        for (elevation, expected) in [
+            (1609., Ok(())),
+            (0., Ok(())),
+            (-430., Ok(())),
+            (-500., Ok(())),
+            (9000., Ok(())),
+            (-500.000001, Err(CoordinatesError::ElevationInvalid)),
+            (9000.000001, Err(CoordinatesError::ElevationInvalid)),
+            (f64::NAN, Err(CoordinatesError::ElevationInvalid)),
+            (f64::INFINITY, Err(CoordinatesError::ElevationInvalid)),
+        ] {
+            let coordinates = denver.with_elevation(elevation);
+            assert_eq!(coordinates.map(|_| ()), expected, "{elevation}");
+            if let Ok(coordinates) = coordinates {
+                assert_eq!(coordinates.elevation, Some(elevation));
+                assert_eq!(coordinates.latitude, denver.latitude);
+                assert_eq!(coordinates.longitude, denver.longitude);
+            }
+        }
+    }
+
+    #[test]
+    fn error_boxes() {
+        fn parse(s: &str) -> Result<Coordinates, Box<dyn std::error::Error>> {
+            Ok(s.parse::<Coordinates>()?)
+        }
+        assert!(parse("39.613319,-105.016647").is_ok());
+        let error = parse("91,0").unwrap_err().to_string();
+        assert!(error.starts_with("CoordinatesError:"), "{error}");
+    }
+
+    #[test]
+    fn deserialization() {
+        let coordinates: Coordinates =
+            serde_json::from_str(r#"{"latitude":39.613319,"longitude":-105.016647}"#).unwrap();
+        assert_eq!(
+            coordinates,
+            Coordinates::new(39.613319, -105.016647).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<Coordinates>(&serde_json::to_string(&coordinates).unwrap())
+                .unwrap(),
+            coordinates
+        );
+        assert_eq!(
+            serde_json::to_string(&coordinates).unwrap(),
+            r#"{"latitude":39.613319,"longitude":-105.016647}"#
+        );
+        let elevated = coordinates.with_elevation(1609.).unwrap();
+        let json = serde_json::to_string(&elevated).unwrap();
+        assert_eq!(
+            json,
+            r#"{"latitude":39.613319,"longitude":-105.016647,"elevation":1609.0}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Coordinates>(&json).unwrap(),
+            elevated
+        );
+        assert_eq!(
+            serde_json::from_str::<Coordinates>(
+                r#"{"latitude":39.613319,"longitude":-105.016647,"elevation":null}"#
+            )
+            .unwrap(),
+            coordinates
+        );
+        for json in [
+            r#"{"latitude":0,"longitude":0,"elevation":10000}"#,
+            r#"{"latitude":500,"longitude":0}"#,
+            r#"{"latitude":0,"longitude":-180.5}"#,
+            r#"{"latitude":0}"#,
+            r#"{"latitude":"N","longitude":0}"#,
+        ] {
+            assert!(serde_json::from_str::<Coordinates>(json).is_err(), "{json}");
+        }
+        let error = serde_json::from_str::<Coordinates>(r#"{"latitude":500,"longitude":0}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("Latitude must be a number between"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn serde_not_self_describing() {
+        let coordinates = Coordinates::new(39.613319, -105.016647).unwrap();
+        for coordinates in [coordinates, coordinates.with_elevation(1609.).unwrap()] {
+            let bytes = bincode::serialize(&coordinates).unwrap();
+            assert_eq!(
+                bincode::deserialize::<Coordinates>(&bytes).unwrap(),
+                coordinates
+            );
+            let denver: chrono_tz::Tz = "America/Denver".parse().unwrap();
+            let date = crate::LocalBadiDate::new(
+                181,
+                crate::BadiMonth::Ala,
+                19,
+                denver,
+                Some(coordinates),
+            )
+            .unwrap();
+            let bytes = bincode::serialize(&date).unwrap();
+            assert_eq!(
+                bincode::deserialize::<crate::LocalBadiDate>(&bytes).unwrap(),
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn parse_coordinates() {
+        let denver = (39.613333, -105.016667);
+        for (s, (latitude, longitude)) in [
+            ("39.613333,-105.016667", denver),
+            ("39.613333, -105.016667", denver),
+            (" 39.613333 -105.016667 ", denver),
+            ("39.613333;-105.016667", denver),
+            ("39.613333 105.016667", (39.613333, 105.016667)),
+            ("-33.8688, 151.2093", (-33.8688, 151.2093)),
+            ("39.613333° N, 105.016667° W", denver),
+            ("39.613333N 105.016667W", denver),
+            ("105.016667W 39.613333N", denver),
+            ("N39.613333 W105.016667", denver),
+            ("39°36'48\"N 105°01'00\"W", denver),
+            ("39° 36′ 48″ N, 105° 1′ 0″ W", denver),
+            ("39°36'48''N 105°01'00''W", denver),
+            ("39°36.8'N 105°1'W", denver),
+            ("39°36'48\" -105°01'00\"", denver),
+            ("39°36'48\" 105°01'00\"", (39.613333, 105.016667)),
+            ("N 39 36 48 W 105 1 0", denver),
+            ("39 36 48 N 105 1 0 W", denver),
+            ("33°52'S 151°12'E", (-33.866667, 151.2)),
+            ("+39.6133-105.0167/", (39.6133, -105.0167)),
+            ("+39.613333-105.016667", denver),
+            ("+3936.8-10501.0/", denver),
+            ("+393648-1050100/", denver),
+            ("+393648-1050100+1600CRSWGS_84/", denver),
+            ("-3352+15112/", (-33.866667, 151.2)),
+            ("+00-000/", (0., 0.)),
+        ] {
+            let coordinates = s.parse::<Coordinates>().expect(s);
+            assert!(
+                (coordinates.latitude - latitude).abs() < 1e-6
+                    && (coordinates.longitude - longitude).abs() < 1e-6,
+                "{s}: {coordinates:?}"
+            );
+        }
+        for s in [
+            "",
+            "39.61",
+            "39.61,",
+            ",105.01",
+            "39.61, -105.01, 5, 6",
+            "39.61 -105.01 5 6",
+            "39.61 --105.01",
+            "39.61 -",
+            "39.61, -105.01, 5N",
+            "39.61, -105.01, 5°",
+            "+393648-1050100+16e2/",
+            "+393648-1050100+/",
+            "39.61 N 105.01 N",
+            "-39.61 S, 105.01 E",
+            "39°60'N 105°W",
+            "39°36'70\"N 105°W",
+            "39'36°N 105°W",
+            "39°°N 105°W",
+            "abc",
+            "39.61x -105.01",
+            "+396-105.0/",
+            "+39.61/",
+            "+5.5-0.25/",
+            "+39.5e1-105/",
+        ] {
+            assert_eq!(
+                s.parse::<Coordinates>(),
+                Err(CoordinatesError::FormatInvalid),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            "91, 0".parse::<Coordinates>(),
+            Err(CoordinatesError::LatitudeInvalid)
+        );
+        assert_eq!(
+            "+00-181/".parse::<Coordinates>(),
+            Err(CoordinatesError::LongitudeInvalid)
+        );
+        assert_eq!(
+            "0, 0, 10000".parse::<Coordinates>(),
+            Err(CoordinatesError::ElevationInvalid)
+        );
+
+        let elevated = Coordinates::new(39.613333, -105.016667)
+            .unwrap()
+            .with_elevation(1609.)
+            .unwrap();
+        for s in [
+            "39.613333,-105.016667,1609",
+            "39.613333, -105.016667, 1609",
+            "39°36'48\"N 105°01'00\"W, 1609",
+            "39°36'48\"N 105°01'00\"W 1609",
+            "39.613333 -105.016667 1609",
+            "39.613333 −105.016667 +1609",
+            "+393648-1050100+1609/",
+            "+393648-1050100+1609.0CRSWGS_84/",
+        ] {
+            let coordinates = s.parse::<Coordinates>().expect(s);
+            assert!(
+                (coordinates.latitude - elevated.latitude).abs() < 1e-6
+                    && (coordinates.longitude - elevated.longitude).abs() < 1e-6,
+                "{s}: {coordinates:?}"
+            );
+            assert_eq!(coordinates.elevation, elevated.elevation, "{s}");
+        }
+        assert_eq!(
+            "31.5,35.5,-430".parse::<Coordinates>().unwrap().elevation,
+            Some(-430.)
+        );
+        assert_eq!(
+            "+31.5+035.5-430/".parse::<Coordinates>().unwrap().elevation,
+            Some(-430.)
+        );
+
+        for coordinates in [
+            Coordinates::new(39.613319, -105.016647).unwrap(),
+            Coordinates::new(-5.5, 0.25).unwrap(),
+            Coordinates::new(0., 0.).unwrap(),
+            Coordinates::new(-90., 180.).unwrap(),
+            elevated,
+            elevated.with_elevation(-12.5).unwrap(),
+        ] {
+            assert_eq!(
+                coordinates.to_string().parse::<Coordinates>(),
+                Ok(coordinates)
+            );
+            assert_eq!(
+                coordinates.iso6709().to_string().parse::<Coordinates>(),
+                Ok(coordinates)
+            );
+        }
+        let coordinates = Coordinates::new(-5.5, 0.25).unwrap();
+        assert_eq!(coordinates.to_string(), "-5.5,0.25");
+        assert_eq!(coordinates.iso6709().to_string(), "-05.500000+000.250000/");
+        assert_eq!(
+            Coordinates::new(39.613319, -105.016647)
+                .unwrap()
+                .iso6709()
+                .to_string(),
+            "+39.613319-105.016647/"
+        );
+        assert_eq!(elevated.to_string(), "39.613333,-105.016667,1609");
+        assert_eq!(
+            elevated.iso6709().to_string(),
+            "+39.613333-105.016667+1609/"
+        );
+    }
 }
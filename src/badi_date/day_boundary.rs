@@ -0,0 +1,414 @@
+use chrono::{DateTime, Days, NaiveTime, TimeDelta, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::{astronomy::*, statics::*, Coordinates};
+
+/// Precision (degrees) of the bisection for the nearest latitude with a sunset
+const NEAREST_LATITUDE_PRECISION: f64 = 0.01;
+
+/// How the boundary between Badí‘ days (the moment one ends and the next begins) is determined for a
+/// [`LocalBadiDate`][`crate::LocalBadiDate`]
+///
+/// Whether the sun actually sets (or twilight ends) is checked for each date. An astronomical boundary cannot be
+/// calculated without [`Coordinates`], in the midnight sun or polar night (when the sun stays above or below the
+/// required altitude all day), or when the sun reaches it only after local midnight (e.g. in early summer near the
+/// polar circles). Nautical twilight then falls back to civil twilight, civil twilight to sunset, and sunset to
+/// [`DayBoundaryPolicy::FixedTime`] at 18:00 (or the time given to [`DayBoundaryPolicy::SunsetOrFixedTime`]), unless
+/// [`DayBoundaryPolicy::NearestLatitude`] follows the sun at the nearest latitude where it sets.
+/// [`DayBoundary::policy`] tells which policy actually determined a boundary.
+///
+/// | Place (latitude)          | March equinox | June solstice          | December solstice      |
+/// |---------------------------|---------------|------------------------|------------------------|
+/// | Tromsø (69.6° N)          | sunset        | midnight sun: fallback | polar night: fallback  |
+/// | Longyearbyen (78.2° N)    | sunset        | midnight sun: fallback | polar night: fallback  |
+/// | Ushuaia (54.8° S)         | sunset        | sunset                 | sunset (about 22:10)   |
+/// | McMurdo Station (77.8° S) | sunset        | polar night: fallback  | midnight sun: fallback |
+///
+/// In the polar night, [`DayBoundaryPolicy::NearestLatitude`] uses the sunset around midday at the nearest latitude
+/// where the sun rises at all.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum DayBoundaryPolicy {
+    /// Sunset (the default): the upper limb of the sun disappears below the horizon, seen from the elevation of the
+    /// [`Coordinates`] (see [`Coordinates::with_elevation`])
+    #[default]
+    Sunset,
+    /// The end of civil twilight: the center of the sun is 6° below the horizon
+    CivilTwilight,
+    /// The end of nautical twilight: the center of the sun is 12° below the horizon
+    NauticalTwilight,
+    /// A fixed local clock time (e.g. 18:00); a time before noon is taken to be after the midnight following the sunset
+    /// it replaces, so the Badí‘ day still begins before its daylight hours
+    /// (e.g. with 02:30 in Denver, 1 Bahá 181 runs from 02:30 on 20 March to 02:30 on 21 March 2024)
+    FixedTime(NaiveTime),
+    /// Sunset where the sun sets, or else (in the midnight sun or polar night) the given local clock time (before noon,
+    /// after midnight like [`DayBoundaryPolicy::FixedTime`]); [`DayBoundaryPolicy::Sunset`] falls back to 18:00
+    SunsetOrFixedTime(NaiveTime),
+    /// Sunset where the sun sets, or else sunset on the same date and longitude at the nearest latitude (toward the
+    /// equator) where it does, so that the day begins in the evening even in the midnight sun or polar night
+    NearestLatitude,
+}
+
+/// The moment one Badí‘ day ends and the next begins, with the [`DayBoundaryPolicy`] that actually determined it
+/// (which may be a fallback of the requested policy; see [`DayBoundaryPolicy`])
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DayBoundary {
+    /// The moment of the boundary in local time
+    pub moment: DateTime<Tz>,
+    /// The policy that determined `moment`
+    pub policy: DayBoundaryPolicy,
+}
+
+impl DayBoundaryPolicy {
+    /// Computes the boundary ending the Badí‘ day whose daylight hours fall on the local date of `date` (in its evening,
+    /// or the next morning for a fixed time before noon) under this policy or its fallbacks
+    pub(crate) fn boundary(
+        &self,
+        coordinates: &Option<Coordinates>,
+        date: DateTime<Tz>,
+    ) -> DayBoundary {
+        let mut policy = *self;
+        loop {
+            if let Some(boundary) = policy.try_boundary(coordinates, date) {
+                return boundary;
+            }
+            policy = match policy {
+                DayBoundaryPolicy::NauticalTwilight => DayBoundaryPolicy::CivilTwilight,
+                DayBoundaryPolicy::CivilTwilight => DayBoundaryPolicy::Sunset,
+                DayBoundaryPolicy::SunsetOrFixedTime(time) => DayBoundaryPolicy::FixedTime(time),
+                _ => DayBoundaryPolicy::FixedTime(*START_OF_DAY_FALLBACK),
+            };
+        }
+    }
+
+    /// Computes the boundary ending the Badí‘ day whose daylight hours fall on the local date of `date` under this
+    /// policy only, if it can be
+    fn try_boundary(
+        &self,
+        coordinates: &Option<Coordinates>,
+        date: DateTime<Tz>,
+    ) -> Option<DayBoundary> {
+        let (event, policy) = match *self {
+            DayBoundaryPolicy::FixedTime(time) => {
+                // a time before noon falls after midnight, on the next local date
+                let date = if time < NaiveTime::from_hms_opt(12, 0, 0).unwrap() {
+                    date + Days::new(1)
+                } else {
+                    date
+                };
+                return Some(DayBoundary {
+                    moment: at_local_time(date, time),
+                    policy: *self,
+                });
+            }
+            DayBoundaryPolicy::NearestLatitude => {
+                return nearest_latitude_sunset(coordinates.as_ref()?, date)
+            }
+            DayBoundaryPolicy::Sunset | DayBoundaryPolicy::SunsetOrFixedTime(_) => {
+                (SolarEvent::Sunset, DayBoundaryPolicy::Sunset)
+            }
+            DayBoundaryPolicy::CivilTwilight => (SolarEvent::CivilDusk, *self),
+            DayBoundaryPolicy::NauticalTwilight => (SolarEvent::NauticalDusk, *self),
+        };
+        let moment = coordinates
+            .as_ref()?
+            .solar_event(event, date.date_naive())?;
+        Some(DayBoundary {
+            moment: on_local_date(date, moment)?,
+            policy,
+        })
+    }
+}
+
+/// Computes sunset on the local date of `date` at the given coordinates if the sun sets there
+/// (as determined by [`DayBoundaryPolicy::Sunset`]), or else at the nearest latitude toward the equator
+/// where it does (as determined by [`DayBoundaryPolicy::NearestLatitude`])
+fn nearest_latitude_sunset(coordinates: &Coordinates, date: DateTime<Tz>) -> Option<DayBoundary> {
+    let &Coordinates {
+        latitude,
+        longitude,
+        elevation,
+    } = coordinates;
+    let sunset_at = |latitude| {
+        let moment = solar_event(
+            latitude,
+            longitude,
+            elevation.unwrap_or(0.),
+            SolarEvent::Sunset,
+            HorizonCriteria::default(),
+            date.date_naive(),
+        )?;
+        on_local_date(date, moment)
+    };
+    if let Some(moment) = sunset_at(latitude) {
+        return Some(DayBoundary {
+            moment,
+            policy: DayBoundaryPolicy::Sunset,
+        });
+    }
+    // bisect between the equator (where the sun always sets) and the latitude (where it does not)
+    let (mut with_sunset, mut moment) = (0., sunset_at(0.)?);
+    let mut without_sunset = latitude;
+    while (without_sunset - with_sunset).abs() > NEAREST_LATITUDE_PRECISION {
+        let middle = (with_sunset + without_sunset) / 2.;
+        match sunset_at(middle) {
+            Some(sunset) => (with_sunset, moment) = (middle, sunset),
+            None => without_sunset = middle,
+        }
+    }
+    Some(DayBoundary {
+        moment,
+        policy: DayBoundaryPolicy::NearestLatitude,
+    })
+}
+
+/// `moment` in the time zone of `date` if it falls on the same local date (before local midnight)
+fn on_local_date(date: DateTime<Tz>, moment: DateTime<Utc>) -> Option<DateTime<Tz>> {
+    let moment = moment.with_timezone(&date.timezone());
+    (moment.date_naive() == date.date_naive()).then_some(moment)
+}
+
+/// The given local time on the local date of `date`; a time skipped by a daylight saving time transition is moved
+/// forward by an hour
+fn at_local_time(date: DateTime<Tz>, time: NaiveTime) -> DateTime<Tz> {
+    date.with_time(time)
+        .earliest()
+        .unwrap_or_else(|| at_local_time(date, time + TimeDelta::hours(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone, Timelike};
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::{BadiDateLike, BadiMonth, FromDateTime, LocalBadiDate, ToDateTime};
+
+    #[test]
+    fn boundary_policies() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        let date = denver.with_ymd_and_hms(2024, 3, 19, 12, 0, 0).unwrap();
+        let sunset = DayBoundaryPolicy::Sunset.boundary(&coords, date);
+        let civil = DayBoundaryPolicy::CivilTwilight.boundary(&coords, date);
+        let nautical = DayBoundaryPolicy::NauticalTwilight.boundary(&coords, date);
+        assert_eq!(sunset.policy, DayBoundaryPolicy::Sunset);
+        assert_eq!(civil.policy, DayBoundaryPolicy::CivilTwilight);
+        assert_eq!(nautical.policy, DayBoundaryPolicy::NauticalTwilight);
+        assert_eq!((sunset.moment.hour(), sunset.moment.minute() / 10), (19, 1));
+        assert!(sunset.moment < civil.moment && civil.moment < nautical.moment);
+        assert_eq!(
+            DayBoundaryPolicy::NearestLatitude.boundary(&coords, date),
+            sunset
+        );
+
+        let seven = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        let fixed = DayBoundaryPolicy::FixedTime(seven);
+        for coordinates in [None, coords] {
+            assert_eq!(
+                fixed.boundary(&coordinates, date),
+                DayBoundary {
+                    moment: denver.with_ymd_and_hms(2024, 3, 19, 19, 0, 0).unwrap(),
+                    policy: fixed,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn boundary_fallbacks() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let date = denver.with_ymd_and_hms(2024, 3, 19, 12, 0, 0).unwrap();
+        let six = DayBoundaryPolicy::FixedTime(*START_OF_DAY_FALLBACK);
+        for policy in [
+            DayBoundaryPolicy::Sunset,
+            DayBoundaryPolicy::CivilTwilight,
+            DayBoundaryPolicy::NauticalTwilight,
+            DayBoundaryPolicy::NearestLatitude,
+        ] {
+            let boundary = policy.boundary(&None, date);
+            assert_eq!(boundary.policy, six);
+            assert_eq!(boundary.moment.hour(), 18);
+        }
+
+        // nautical twilight does not end in Edinburgh around the June solstice
+        let london: Tz = "Europe/London".parse().unwrap();
+        let edinburgh = Some(Coordinates::new(55.953251, -3.188267).unwrap());
+        let solstice = london.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+        assert_eq!(
+            DayBoundaryPolicy::NauticalTwilight
+                .boundary(&edinburgh, solstice)
+                .policy,
+            DayBoundaryPolicy::CivilTwilight
+        );
+
+        // the midnight sun in Tromsø
+        let oslo: Tz = "Europe/Oslo".parse().unwrap();
+        let tromso = Some(Coordinates::new(69.6496, 18.956).unwrap());
+        let midsummer = oslo.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+        assert_eq!(
+            DayBoundaryPolicy::Sunset
+                .boundary(&tromso, midsummer)
+                .policy,
+            six
+        );
+        let nearest = DayBoundaryPolicy::NearestLatitude.boundary(&tromso, midsummer);
+        assert_eq!(nearest.policy, DayBoundaryPolicy::NearestLatitude);
+        assert_eq!(nearest.moment.date_naive(), midsummer.date_naive());
+        assert!(nearest.moment.hour() >= 22, "{}", nearest.moment);
+        // the sun sets in Tromsø in March
+        let equinox = oslo.with_ymd_and_hms(2024, 3, 19, 12, 0, 0).unwrap();
+        assert_eq!(
+            DayBoundaryPolicy::NearestLatitude
+                .boundary(&tromso, equinox)
+                .policy,
+            DayBoundaryPolicy::Sunset
+        );
+    }
+
+    #[test]
+    fn polar_day_and_night() {
+        let seven = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        let six = DayBoundaryPolicy::FixedTime(*START_OF_DAY_FALLBACK);
+        let sunset = DayBoundaryPolicy::Sunset;
+        let nearest = DayBoundaryPolicy::NearestLatitude;
+        for (timezone, latitude, longitude, dates) in [
+            // Tromsø: midnight sun from about 20 May to 22 July, polar night from about 27 November to 15 January
+            (
+                "Europe/Oslo",
+                69.6496,
+                18.956,
+                [
+                    ((3, 19), Some((17, 59))),
+                    ((4, 25), Some((21, 37))),
+                    ((6, 21), None),
+                    ((9, 22), Some((18, 45))),
+                    ((11, 15), Some((13, 28))),
+                    ((12, 21), None),
+                ],
+            ),
+            // Longyearbyen: midnight sun from about 20 April to 23 August, polar night from about 26 October to 16 February
+            (
+                "Arctic/Longyearbyen",
+                78.2232,
+                15.6267,
+                [
+                    ((2, 20), Some((14, 14))),
+                    ((3, 19), Some((18, 18))),
+                    ((4, 25), None),
+                    ((6, 21), None),
+                    ((10, 20), Some((14, 58))),
+                    ((12, 21), None),
+                ],
+            ),
+            // Ushuaia: the sun sets every day
+            (
+                "America/Argentina/Ushuaia",
+                -54.8019,
+                -68.303,
+                [
+                    ((3, 19), Some((19, 46))),
+                    ((6, 21), Some((17, 11))),
+                    ((9, 22), Some((19, 32))),
+                    ((11, 15), Some((21, 20))),
+                    ((12, 21), Some((22, 11))),
+                    ((1, 15), Some((22, 2))),
+                ],
+            ),
+            // McMurdo Station: polar night from about 24 April to 20 August, midnight sun from about 20 October to 22 February
+            (
+                "Antarctica/McMurdo",
+                -77.8419,
+                166.6863,
+                [
+                    ((3, 19), Some((20, 22))),
+                    ((6, 21), None),
+                    ((8, 20), Some((14, 14))),
+                    ((9, 22), Some((19, 0))),
+                    ((12, 21), None),
+                    ((1, 15), None),
+                ],
+            ),
+        ] {
+            let timezone: Tz = timezone.parse().unwrap();
+            let coords = Some(Coordinates::new(latitude, longitude).unwrap());
+            for ((month, day), expected) in dates {
+                let date = timezone
+                    .with_ymd_and_hms(2024, month, day, 12, 0, 0)
+                    .unwrap();
+                let boundary = sunset.boundary(&coords, date);
+                let nearest_boundary = nearest.boundary(&coords, date);
+                let fixed = DayBoundaryPolicy::SunsetOrFixedTime(seven).boundary(&coords, date);
+                let description = format!("{latitude} {month}-{day}: {}", boundary.moment);
+                match expected {
+                    Some((hour, minute)) => {
+                        assert_eq!(boundary.policy, sunset, "{description}");
+                        let minutes = boundary.moment.hour() * 60 + boundary.moment.minute();
+                        assert!(minutes.abs_diff(hour * 60 + minute) <= 2, "{description}");
+                        assert_eq!(nearest_boundary, boundary, "{description}");
+                        assert_eq!(fixed, boundary, "{description}");
+                    }
+                    None => {
+                        assert_eq!(boundary.policy, six, "{description}");
+                        assert_eq!(boundary.moment.hour(), 18, "{description}");
+                        assert_eq!(nearest_boundary.policy, nearest, "{description}");
+                        assert_eq!(
+                            nearest_boundary.moment.date_naive(),
+                            date.date_naive(),
+                            "{description}"
+                        );
+                        assert_eq!(
+                            fixed.policy,
+                            DayBoundaryPolicy::FixedTime(seven),
+                            "{description}"
+                        );
+                        assert_eq!(fixed.moment.hour(), 19, "{description}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_time_in_daylight_saving_gap() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let date = denver.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+        let policy = DayBoundaryPolicy::FixedTime(NaiveTime::from_hms_opt(2, 30, 0).unwrap());
+        // a time before noon falls after midnight: ʻAláʼ 10 begins at 02:30 (03:30 after the transition) on 10 March
+        assert_eq!(
+            policy.boundary(&None, date).moment,
+            denver.with_ymd_and_hms(2024, 3, 10, 3, 30, 0).unwrap()
+        );
+        for (hour, day) in [(3, 9), (4, 10), (20, 10)] {
+            let date = denver.with_ymd_and_hms(2024, 3, 10, hour, 0, 0).unwrap();
+            let badi_date = LocalBadiDate::from_datetime_with_policy(date, None, policy).unwrap();
+            assert_eq!(
+                (badi_date.month(), badi_date.day()),
+                (BadiMonth::Ala, day),
+                "{date}"
+            );
+            assert!(
+                badi_date.start() <= date && date < badi_date.end(),
+                "{date}"
+            );
+        }
+        // so 1 Bahá 181 runs from 02:30 on 20 March to 02:30 on 21 March 2024, between sunset and sunrise
+        let naw_ruz = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None)
+            .unwrap()
+            .with_day_boundary_policy(policy);
+        assert_eq!(
+            naw_ruz.start(),
+            denver.with_ymd_and_hms(2024, 3, 20, 2, 30, 0).unwrap()
+        );
+        assert_eq!(
+            naw_ruz.end(),
+            denver.with_ymd_and_hms(2024, 3, 21, 2, 30, 0).unwrap()
+        );
+        for date in [naw_ruz.start(), naw_ruz.end() - TimeDelta::minutes(1)] {
+            assert_eq!(
+                LocalBadiDate::from_datetime_with_policy(date, None, policy).unwrap(),
+                naw_ruz
+            );
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use crate::{BadiMonth, BadiWeekday, CalendarRules, VahidYear};
+
+/// Default locale for names in [`BadiDateLike::format`][`crate::BadiDateLike::format`] (English transliteration)
+pub(crate) const FORMAT_DEFAULT_LOCALE: &str = "tl";
+
+/// A [`BadiDateLike`][`crate::BadiDateLike`] formatted with a pattern; the pattern is only evaluated
+/// when displayed (like [`chrono::format::DelayedFormat`])
+///
+/// Supported specifiers:
+///
+/// | Spec. | Example       | Description                                                        |
+/// |-------|---------------|--------------------------------------------------------------------|
+/// | `%Y`  | `181`         | Bahá’í Era/Badi year                                               |
+/// | `%m`  | `05`          | Month number, zero-padded to 2 digits (`00` for Ayyám-i-Há)        |
+/// | `%B`  | `Núr`         | Month name in the locale                                           |
+/// | `%d`  | `07`          | Day of the month, zero-padded to 2 digits                          |
+/// | `%e`  | `7`           | Day of the month                                                   |
+/// | `%D`  | `Kalimát`     | Day name in the locale (the day number during Ayyám-i-Há)          |
+/// | `%A`  | `Kamál`       | Weekday name in the locale                                         |
+/// | `%u`  | `3`           | Weekday number from Jalál (Saturday) = 1 to Istiqlál (Friday) = 7  |
+/// | `%j`  | `083`         | Day of the year, zero-padded to 3 digits                           |
+/// | `%y`  | `10`          | Year in the Váḥid [1 - 19]                                         |
+/// | `%N`  | `Ḥubb`        | Year name in the locale                                            |
+/// | `%V`  | `10`          | Váḥid in the Kull-i-Shay [1 - 19]                                  |
+/// | `%K`  | `1`           | Kull-i-Shay                                                        |
+/// | `%E`  | `B.E.`        | Era                                                                |
+/// | `%%`  | `%`           | A literal percent sign                                             |
+///
+/// Any other specifier results in a [`fmt::Error`] when displayed.
+#[derive(Clone, Debug)]
+pub struct BadiDelayedFormat<'a> {
+    year: u16,
+    month: BadiMonth,
+    day: u16,
+    day_of_year: u16,
+    calendar_rules: CalendarRules,
+    pattern: &'a str,
+    locale: &'a str,
+}
+
+impl<'a> BadiDelayedFormat<'a> {
+    pub(crate) fn new(
+        year: u16,
+        month: BadiMonth,
+        day: u16,
+        day_of_year: u16,
+        calendar_rules: CalendarRules,
+        pattern: &'a str,
+        locale: &'a str,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            day_of_year,
+            calendar_rules,
+            pattern,
+            locale,
+        }
+    }
+
+    fn weekday(&self) -> BadiWeekday {
+        self.calendar_rules.weekday(self.year, self.day_of_year)
+    }
+
+    fn vahid_year(&self) -> Result<VahidYear, fmt::Error> {
+        VahidYear::new(self.year).map_err(|_| fmt::Error)
+    }
+}
+
+impl fmt::Display for BadiDelayedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chars = self.pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                write!(f, "{c}")?;
+                continue;
+            }
+            match chars.next().ok_or(fmt::Error)? {
+                'Y' => write!(f, "{}", self.year)?,
+                'm' => write!(f, "{:0>2}", self.month.number())?,
+                'B' => write!(f, "{}", self.month.name(self.locale))?,
+                'd' => write!(f, "{:0>2}", self.day)?,
+                'e' => write!(f, "{}", self.day)?,
+                'D' => match BadiMonth::try_from(self.day as u8) {
+                    Ok(day) if self.month != BadiMonth::AyyamIHa => {
+                        write!(f, "{}", day.name(self.locale))?
+                    }
+                    _ => write!(f, "{}", self.day)?,
+                },
+                'A' => write!(f, "{}", self.weekday().name(self.locale))?,
+                'u' => write!(f, "{}", self.weekday().number_from_jalal())?,
+                'j' => write!(f, "{:0>3}", self.day_of_year)?,
+                'y' => write!(f, "{}", self.vahid_year()?.year_in_vahid())?,
+                'N' => write!(f, "{}", self.vahid_year()?.name(self.locale))?,
+                'V' => write!(f, "{}", self.vahid_year()?.vahid())?,
+                'K' => write!(f, "{}", self.vahid_year()?.kull_i_shay())?,
+                'E' => write!(f, "B.E.")?,
+                '%' => write!(f, "%")?,
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    use crate::{BadiDate, BadiDateLike, BadiMonth, FromDateTime, LocalBadiDate};
+
+    #[test]
+    fn format() {
+        // Monday 10 June 2024
+        let badi_date = BadiDate::new(181, BadiMonth::Nur, 7).unwrap();
+        for (pattern, expected) in [
+            ("%Y-%m-%d", "181-05-07"),
+            ("%e %B %Y %E", "7 Núr 181 B.E."),
+            ("Day of %D, Month of %B", "Day of Kalimát, Month of Núr"),
+            ("%A", "Kamál"),
+            ("%u", "3"),
+            ("%j", "083"),
+            (
+                "Year %N (%y) of Váḥid %V of Kull-i-Shay %K",
+                "Year Ḥubb (10) of Váḥid 10 of Kull-i-Shay 1",
+            ),
+            ("100%% %Y", "100% 181"),
+            ("", ""),
+        ] {
+            assert_eq!(badi_date.format(pattern).to_string(), expected);
+        }
+        assert_eq!(
+            badi_date.format_localized("%A, %e %B %Y", "en").to_string(),
+            "Perfection, 7 Light 181"
+        );
+
+        let ayyamiha = BadiDate::new(180, BadiMonth::AyyamIHa, 4).unwrap();
+        assert_eq!(
+            ayyamiha.format("%Y-%m-%d: %D %B").to_string(),
+            "180-00-04: 4 Ayyám-i-Há"
+        );
+
+        let mut output = String::new();
+        assert!(write!(output, "{}", badi_date.format("%Q")).is_err());
+        assert!(write!(output, "{}", badi_date.format("%")).is_err());
+
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let date = denver.with_ymd_and_hms(2024, 6, 7, 21, 0, 0).unwrap();
+        let local = LocalBadiDate::from_datetime(date, None).unwrap();
+        assert_eq!(local.format("%A %e %B %Y").to_string(), "Jalál 5 Núr 181");
+    }
+}
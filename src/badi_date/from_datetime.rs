@@ -1,48 +1,270 @@
 use chrono::{DateTime, Datelike};
 use chrono_tz::Tz;
 
-use crate::{statics::*, BadiDateError, Coordinates, LocalBadiDate};
+use crate::{
+    statics::*, BadiDateError, CalendarRules, Coordinates, DayBoundaryPolicy, LocalBadiDate,
+};
 
 use super::util::*;
 
 /// Provides methods to create a [`LocalBadiDate`] from a Gregorian [`DateTime`]
 pub trait FromDateTime {
-    /// Create a new LocalBadiDate given a local time-zoned date and coordinates
+    /// Create a new LocalBadiDate given a local time-zoned date and coordinates, under [`CalendarRules::default()`]
+    /// (so a date before Naw-Rúz 172 B.E. in 2015 converts under the unified calendar, often a day off the
+    /// Western calendar then observed; see [`FromDateTime::from_datetime_with_rules`])
     fn from_datetime(
         date: DateTime<Tz>,
         coordinates: Option<Coordinates>,
-    ) -> Result<LocalBadiDate, BadiDateError>;
-}
+    ) -> Result<LocalBadiDate, BadiDateError> {
+        Self::from_datetime_with_rules_and_policy(
+            date,
+            coordinates,
+            CalendarRules::default(),
+            DayBoundaryPolicy::default(),
+        )
+    }
+
+    /// Create a new LocalBadiDate given a local time-zoned date, coordinates, and [`CalendarRules`]
+    fn from_datetime_with_rules(
+        date: DateTime<Tz>,
+        coordinates: Option<Coordinates>,
+        calendar_rules: CalendarRules,
+    ) -> Result<LocalBadiDate, BadiDateError> {
+        Self::from_datetime_with_rules_and_policy(
+            date,
+            coordinates,
+            calendar_rules,
+            DayBoundaryPolicy::default(),
+        )
+    }
 
-impl FromDateTime for LocalBadiDate {
+    /// Create a new LocalBadiDate given a local time-zoned date, coordinates, and [`DayBoundaryPolicy`], under
+    /// [`CalendarRules::default()`] (also before 172 B.E.; see [`FromDateTime::from_datetime`])
+    fn from_datetime_with_policy(
+        date: DateTime<Tz>,
+        coordinates: Option<Coordinates>,
+        day_boundary_policy: DayBoundaryPolicy,
+    ) -> Result<LocalBadiDate, BadiDateError> {
+        Self::from_datetime_with_rules_and_policy(
+            date,
+            coordinates,
+            CalendarRules::default(),
+            day_boundary_policy,
+        )
+    }
+
+    /// Create a new LocalBadiDate given a local time-zoned date, coordinates, [`CalendarRules`],
+    /// and [`DayBoundaryPolicy`]
     // Bahá’í Calendar 2024: https://www.bahai.org/action/devotional-life/calendar/pdf-calendar
     // https://www.bahai.org/action/devotional-life/calendar
     // adapted from https://github.com/Soroosh/badi_date/blob/main/lib/badi_date.dart
     // and https://github.com/janrg/badiDate/blob/master/src/badiDate.ts
-    fn from_datetime(
+    fn from_datetime_with_rules_and_policy(
         date: DateTime<Tz>,
         coordinates: Option<Coordinates>,
-    ) -> Result<Self, BadiDateError> {
-        if date < *FIRST_GREGORIAN_DATE_SUPPORTED || date > *LAST_GREGORIAN_DATE_SUPPORTED {
+        calendar_rules: CalendarRules,
+        day_boundary_policy: DayBoundaryPolicy,
+    ) -> Result<LocalBadiDate, BadiDateError> {
+        if date.year() < YEAR_ONE_IN_GREGORIAN || date.year() > LAST_GREGORIAN_YEAR_SUPPORTED {
             return Err(BadiDateError::DateNotSupported);
         }
-        let last_sunset = get_last_sunset(&coordinates, date);
-        // let next_sunset = get_next_sunset(&coordinates, date);
-        let last_naw_ruz = get_sunset_of_last_naw_ruz(&coordinates, date);
-        let year = (last_naw_ruz.year() - YEAR_ZERO_IN_GREGORIAN) as u8;
+        let last_boundary = get_last_day_boundary(&coordinates, day_boundary_policy, date).moment;
+        let last_naw_ruz =
+            get_start_of_last_naw_ruz(&coordinates, day_boundary_policy, date, calendar_rules)
+                .moment;
+        // year 0 (before Naw-Rúz 1 B.E.) and years after the last supported are rejected below
+        let year = (last_naw_ruz.year() - YEAR_ZERO_IN_GREGORIAN) as u16;
         let day_of_year: u16 =
-            1 + (last_sunset.date_naive() - last_naw_ruz.date_naive()).num_days() as u16;
-        let (month, day) = month_and_day_from_doy(year, day_of_year)?;
-        Self::new(year, month, day, date.timezone(), coordinates)
+            1 + (last_boundary.date_naive() - last_naw_ruz.date_naive()).num_days() as u16;
+        let (month, day) = month_and_day_from_doy(year, day_of_year, calendar_rules)?;
+        LocalBadiDate::new_with_rules(
+            year,
+            month,
+            day,
+            date.timezone(),
+            coordinates,
+            calendar_rules,
+        )
+        .map(|badi_date| badi_date.with_day_boundary_policy(day_boundary_policy))
     }
 }
 
+impl FromDateTime for LocalBadiDate {}
+
 #[cfg(test)]
 mod tests {
-    use chrono::{DateTime, TimeZone};
+    use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, TimeZone};
     use chrono_tz::Tz;
 
-    use crate::{BadiMonth, Coordinates, FromDateTime, LocalBadiDate, ToDateTime};
+    use crate::{
+        BadiDate, BadiDateLike, BadiDays, BadiMonth, CalendarRules, Coordinates, DayBoundary,
+        DayBoundaryPolicy, FromDateTime, LocalBadiDate, LocalBadiDateLike, ToDateTime,
+    };
+
+    #[test]
+    fn badi_date_from_datetime_beyond_published_years() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        for year in [2065, 2080, 2098, 2099, 2500, 2843] {
+            let date = denver.with_ymd_and_hms(year, 6, 1, 12, 0, 0).unwrap();
+            let badi_date = LocalBadiDate::from_datetime(date, coords).unwrap();
+            assert_eq!(badi_date.year() as i32, year - 1843);
+            assert!(badi_date.start() <= date && date <= badi_date.end());
+        }
+    }
+
+    #[test]
+    fn supported_range_matches_naive_dates() {
+        let tehran: Tz = "Asia/Tehran".parse().unwrap();
+        for (rules, first_day) in [
+            (CalendarRules::Unified2015, 20),
+            (CalendarRules::WesternGregorianPinned, 21),
+        ] {
+            let first = NaiveDate::from_ymd_opt(1844, 3, first_day).unwrap();
+            let noon = tehran
+                .with_ymd_and_hms(1844, 3, first_day, 12, 0, 0)
+                .unwrap();
+            let badi_date = LocalBadiDate::from_datetime_with_rules(noon, None, rules).unwrap();
+            assert_eq!(
+                (badi_date.year(), badi_date.month(), badi_date.day()),
+                (1, BadiMonth::Baha, 1)
+            );
+            assert_eq!(
+                BadiDate::from_naive_date_with_rules(first, rules).unwrap(),
+                BadiDate::new_with_rules(1, BadiMonth::Baha, 1, rules).unwrap()
+            );
+            let before = badi_date.start() - TimeDelta::minutes(1);
+            assert!(LocalBadiDate::from_datetime_with_rules(before, None, rules).is_err());
+            assert!(
+                BadiDate::from_naive_date_with_rules(first.pred_opt().unwrap(), rules).is_err()
+            );
+
+            let last = BadiDate::new_with_rules(1000, BadiMonth::Ala, 19, rules).unwrap();
+            let last_date = NaiveDate::from(last.clone());
+            let noon = tehran.from_local_datetime(&last_date.and_hms_opt(12, 0, 0).unwrap());
+            let badi_date =
+                LocalBadiDate::from_datetime_with_rules(noon.unwrap(), None, rules).unwrap();
+            assert_eq!(
+                (badi_date.year(), badi_date.day_of_year()),
+                (1000, last.day_of_year())
+            );
+            let after = badi_date.end() + TimeDelta::minutes(1);
+            assert!(LocalBadiDate::from_datetime_with_rules(after, None, rules).is_err());
+            assert!(
+                BadiDate::from_naive_date_with_rules(last_date.succ_opt().unwrap(), rules).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn elevation_delays_day_boundaries() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let sea_level = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        let elevated = sea_level.map(|coords| coords.with_elevation(1609.).unwrap());
+        let naw_ruz = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, sea_level).unwrap();
+        let elevated_naw_ruz =
+            LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, elevated).unwrap();
+        let delay = elevated_naw_ruz.start() - naw_ruz.start();
+        assert!((6..=8).contains(&delay.num_minutes()), "{delay}");
+        assert!((6..=8).contains(&(elevated_naw_ruz.end() - naw_ruz.end()).num_minutes()));
+
+        let between = naw_ruz.start() + TimeDelta::minutes(3);
+        assert_eq!(
+            LocalBadiDate::from_datetime(between, sea_level).unwrap(),
+            naw_ruz
+        );
+        assert_eq!(
+            LocalBadiDate::from_datetime(between, elevated).unwrap(),
+            LocalBadiDate::new(180, BadiMonth::Ala, 19, denver, elevated).unwrap()
+        );
+    }
+
+    #[test]
+    fn day_boundary_policy() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        // after sunset (about 19:12) but before the end of civil twilight (about 19:39)
+        let date = denver.with_ymd_and_hms(2024, 3, 19, 19, 25, 0).unwrap();
+        let naw_ruz = LocalBadiDate::from_datetime(date, coords).unwrap();
+        assert_eq!(naw_ruz.month(), BadiMonth::Baha);
+        assert_eq!(naw_ruz.start_boundary().policy, DayBoundaryPolicy::Sunset);
+
+        let civil = DayBoundaryPolicy::CivilTwilight;
+        let ala_19 = LocalBadiDate::from_datetime_with_policy(date, coords, civil).unwrap();
+        assert_eq!(
+            ala_19,
+            LocalBadiDate::new(180, BadiMonth::Ala, 19, denver, coords)
+                .unwrap()
+                .with_day_boundary_policy(civil)
+        );
+        assert_eq!(ala_19.day_boundary_policy(), civil);
+        assert_eq!(ala_19.end_boundary().policy, civil);
+        assert!(ala_19.start() <= date && date < ala_19.end());
+        let next_day = ala_19.clone() + BadiDays(1);
+        assert_eq!(next_day.day_boundary_policy(), civil);
+        assert_eq!(next_day.start(), ala_19.end());
+
+        let half_past_seven =
+            DayBoundaryPolicy::FixedTime(NaiveTime::from_hms_opt(19, 30, 0).unwrap());
+        let fixed = LocalBadiDate::from_datetime_with_policy(date, None, half_past_seven).unwrap();
+        assert_eq!((fixed.month(), fixed.day()), (BadiMonth::Ala, 19));
+        assert_eq!(
+            fixed.end_boundary(),
+            DayBoundary {
+                moment: denver.with_ymd_and_hms(2024, 3, 19, 19, 30, 0).unwrap(),
+                policy: half_past_seven,
+            }
+        );
+
+        // without coordinates, sunset falls back to 18:00
+        let fallback = LocalBadiDate::from_datetime(date, None).unwrap();
+        assert_eq!(
+            fallback,
+            LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap()
+        );
+        let start = fallback.start_boundary();
+        assert_eq!(
+            start.policy,
+            DayBoundaryPolicy::FixedTime(NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+        );
+        assert_eq!(
+            start.moment,
+            denver.with_ymd_and_hms(2024, 3, 19, 18, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn polar_day_and_night_are_continuous() {
+        for (timezone, latitude, longitude) in [
+            ("Europe/Oslo", 69.6496, 18.956),
+            ("Antarctica/McMurdo", -77.8419, 166.6863),
+        ] {
+            let timezone: Tz = timezone.parse().unwrap();
+            let coords = Some(Coordinates::new(latitude, longitude).unwrap());
+            for policy in [
+                DayBoundaryPolicy::Sunset,
+                DayBoundaryPolicy::NearestLatitude,
+            ] {
+                let first = timezone.with_ymd_and_hms(2024, 1, 1, 15, 0, 0).unwrap();
+                let mut previous =
+                    LocalBadiDate::from_datetime_with_policy(first, coords, policy).unwrap();
+                for _ in 0..366 {
+                    // each day begins where the previous one ends, even around the midnight sun and polar night
+                    let date = previous.end();
+                    let badi_date =
+                        LocalBadiDate::from_datetime_with_policy(date, coords, policy).unwrap();
+                    assert_eq!(badi_date.start(), date, "{date}: {badi_date}");
+                    assert!(date < badi_date.end(), "{date}: {badi_date}");
+                    assert_eq!(
+                        badi_date.to_epoch_days() - previous.to_epoch_days(),
+                        1,
+                        "{date}: {badi_date}"
+                    );
+                    previous = badi_date;
+                }
+            }
+        }
+    }
 
     #[test]
     fn badi_date_from_datetime() {
@@ -52,77 +274,77 @@ mod tests {
             (
                 "2024 Naw Ruz before sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 3, 19, 12, 0, 0).unwrap(),
-                LocalBadiDate::new(180, BadiMonth::Month(19), 19, denver, coords).unwrap(),
+                LocalBadiDate::new(180, BadiMonth::Ala, 19, denver, coords).unwrap(),
             ),
             (
                 "2024 Naw Ruz after sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 3, 19, 20, 0, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(1), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, coords).unwrap(),
             ),
             (
                 "2024 day after Naw Ruz before sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(1), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, coords).unwrap(),
             ),
             (
                 "2024 day after Naw Ruz after sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 3, 20, 20, 0, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(1), 2, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Baha, 2, denver, coords).unwrap(),
             ),
             (
                 "2024 Jalal before sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 4, 7, 10, 32, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(1), 19, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Baha, 19, denver, coords).unwrap(),
             ),
             (
                 "2024 Jalal after sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 4, 7, 19, 32, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(2), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Jalal, 1, denver, coords).unwrap(),
             ),
             (
                 "Feast of ‘Izzat (Might) 181 B.E. before sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 9, 6, 10, 24, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(9), 19, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Asma, 19, denver, coords).unwrap(),
             ),
             (
                 "Feast of ‘Izzat (Might) 181 B.E. after sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 9, 6, 19, 24, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(10), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Izzat, 1, denver, coords).unwrap(),
             ),
             (
                 "Feast of ‘Izzat (Might) 181 B.E. after midnight".to_string(),
                 denver.with_ymd_and_hms(2024, 9, 7, 0, 0, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(10), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Izzat, 1, denver, coords).unwrap(),
             ),
             (
                 "Feast of Sharaf (Honour) 181 B.E. before sunset".to_string(),
                 denver.with_ymd_and_hms(2024, 12, 29, 15, 45, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(15), 19, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Masail, 19, denver, coords).unwrap(),
             ),
             (
                 "Feast of Sharaf (Honour) 181 B.E.".to_string(),
                 denver.with_ymd_and_hms(2024, 12, 29, 16, 46, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(16), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Sharaf, 1, denver, coords).unwrap(),
             ),
             (
                 "some day".to_string(),
                 denver.with_ymd_and_hms(2024, 6, 4, 0, 15, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(5), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Nur, 1, denver, coords).unwrap(),
             ),
             (
                 "Feast of Jalál (Glory) 182 B.E.".to_string(),
-                denver.with_ymd_and_hms(2025, 4, 7, 19, 30, 0).unwrap(),
-                LocalBadiDate::new(182, BadiMonth::Month(2), 1, denver, coords).unwrap(),
+                denver.with_ymd_and_hms(2025, 4, 7, 19, 35, 0).unwrap(),
+                LocalBadiDate::new(182, BadiMonth::Jalal, 1, denver, coords).unwrap(),
             ),
             (
                 "Feast day".to_string(),
                 denver.with_ymd_and_hms(2024, 6, 3, 20, 30, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(5), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Nur, 1, denver, coords).unwrap(),
             ),
             (
                 "Mulk day 19 2025".to_string(),
                 denver.with_ymd_and_hms(2025, 2, 24, 16, 30, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(18), 19, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Mulk, 19, denver, coords).unwrap(),
             ),
             (
                 "Ayyám-i-Há day 1 2025".to_string(),
@@ -137,7 +359,7 @@ mod tests {
             (
                 "Alá day 1 2025".to_string(),
                 denver.with_ymd_and_hms(2025, 2, 28, 18, 30, 0).unwrap(),
-                LocalBadiDate::new(181, BadiMonth::Month(19), 1, denver, coords).unwrap(),
+                LocalBadiDate::new(181, BadiMonth::Ala, 1, denver, coords).unwrap(),
             ),
         ];
 
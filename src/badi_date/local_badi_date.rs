@@ -1,23 +1,59 @@
 use std::fmt;
 
-use super::util::*;
+use super::{parse::settings_suffix, util::*};
 use crate::{
-    BadiDateError, BadiDateLike, BadiMonth, Coordinates, HolyDayProviding, LocalBadiDateLike,
-    ToDateTime,
+    BadiDateError, BadiDateLike, BadiMonth, CalendarRules, Coordinates, DayBoundaryPolicy,
+    HolyDayProviding, LocalBadiDateLike, ToDateTime,
 };
-use chrono_tz::{OffsetName, Tz};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 /// A structure that holds a date in the Badí‘ (Bahá’í) calendar with associated time zone and optional coordinates
+/// (deserialization is validated like [`LocalBadiDate::new_with_rules`])
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "RawLocalBadiDate")]
 pub struct LocalBadiDate {
-    year: u8,
+    year: u16,
     month: BadiMonth,
     day: u16,
     #[serde(skip)]
     day_of_year: u16,
     timezone: Tz,
     coordinates: Option<Coordinates>,
+    #[serde(default)]
+    calendar_rules: CalendarRules,
+    #[serde(default)]
+    day_boundary_policy: DayBoundaryPolicy,
+}
+
+/// Unvalidated fields of a [`LocalBadiDate`] as deserialized
+#[derive(Deserialize)]
+struct RawLocalBadiDate {
+    year: u16,
+    month: BadiMonth,
+    day: u16,
+    timezone: Tz,
+    coordinates: Option<Coordinates>,
+    #[serde(default)]
+    calendar_rules: CalendarRules,
+    #[serde(default)]
+    day_boundary_policy: DayBoundaryPolicy,
+}
+
+impl TryFrom<RawLocalBadiDate> for LocalBadiDate {
+    type Error = BadiDateError;
+
+    fn try_from(raw: RawLocalBadiDate) -> Result<Self, Self::Error> {
+        Self::new_with_rules(
+            raw.year,
+            raw.month,
+            raw.day,
+            raw.timezone,
+            raw.coordinates,
+            raw.calendar_rules,
+        )
+        .map(|date| date.with_day_boundary_policy(raw.day_boundary_policy))
+    }
 }
 
 impl Eq for LocalBadiDate {}
@@ -29,6 +65,8 @@ impl PartialEq for LocalBadiDate {
             && self.day == other.day
             && self.day_of_year == other.day_of_year
             && self.timezone == other.timezone
+            && self.calendar_rules == other.calendar_rules
+            && self.day_boundary_policy == other.day_boundary_policy
     }
 }
 
@@ -46,42 +84,60 @@ impl PartialOrd for LocalBadiDate {
     }
 }
 
+/// "year-month-day", its non-default [`CalendarRules`] and [`DayBoundaryPolicy`] in brackets if any, its coordinates
+/// if any, and the IANA time zone (e.g. `181-01-01 [NauticalTwilight] 39.613319,-105.016647 America/Denver`),
+/// so that [`LocalBadiDate::from_str`][`std::str::FromStr::from_str`] reads it back
 impl fmt::Display for LocalBadiDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:0>3}-{:0>2}-{:0>2} {}{}",
+            "{:0>3}-{:0>2}-{:0>2}{} {}{}",
             self.year,
-            match self.month {
-                BadiMonth::Month(month) => month,
-                BadiMonth::AyyamIHa => 0,
-            },
+            self.month.number(),
             self.day,
+            settings_suffix(self.calendar_rules, self.day_boundary_policy),
             match self.coordinates {
                 Some(coords) => format!("{} ", coords),
                 None => String::default(),
             },
-            self.start()
-                .offset()
-                .abbreviation()
-                .map(String::from)
-                .unwrap_or_else(|| self.start().offset().to_string()),
+            self.timezone.name(),
         )
     }
 }
 
 impl LocalBadiDate {
     /// Create a new [`LocalBadiDate`] given day, [`BadiMonth`], year,
-    /// with optionals [`Coordinates`], [`chrono_tz::Tz`]; checks for validity
+    /// with optionals [`Coordinates`], [`chrono_tz::Tz`]; checks for validity under [`CalendarRules::default()`]
+    /// (the unified calendar, also before 172 B.E.; see [`LocalBadiDate::new_with_rules`] for the Western one)
     pub fn new(
-        year: u8,
+        year: u16,
         month: BadiMonth,
         day: u16,
         timezone: Tz,
         coordinates: Option<Coordinates>,
     ) -> Result<Self, BadiDateError> {
-        validate(year, month, day)?;
-        let day_of_year = day_of_year(year, &month, day);
+        Self::new_with_rules(
+            year,
+            month,
+            day,
+            timezone,
+            coordinates,
+            CalendarRules::default(),
+        )
+    }
+
+    /// Create a new [`LocalBadiDate`] given day, [`BadiMonth`], year,
+    /// with optionals [`Coordinates`], [`chrono_tz::Tz`], and [`CalendarRules`]; checks for validity
+    pub fn new_with_rules(
+        year: u16,
+        month: BadiMonth,
+        day: u16,
+        timezone: Tz,
+        coordinates: Option<Coordinates>,
+        calendar_rules: CalendarRules,
+    ) -> Result<Self, BadiDateError> {
+        validate(year, month, day, calendar_rules)?;
+        let day_of_year = day_of_year(year, &month, day, calendar_rules);
         Ok(Self {
             year,
             month,
@@ -89,12 +145,37 @@ impl LocalBadiDate {
             coordinates,
             timezone,
             day_of_year,
+            calendar_rules,
+            day_boundary_policy: DayBoundaryPolicy::default(),
         })
     }
+
+    /// Copy with the given [`DayBoundaryPolicy`] determining when the day begins and ends
+    /// (see [`ToDateTime`]; the default is [`DayBoundaryPolicy::Sunset`])
+    pub fn with_day_boundary_policy(&self, day_boundary_policy: DayBoundaryPolicy) -> Self {
+        Self {
+            day_boundary_policy,
+            ..self.clone()
+        }
+    }
+
+    /// Create a [`LocalBadiDate`] with the given year, month, and day and the time zone, coordinates,
+    /// rules, and day boundary policy of this one; checks for validity
+    fn new_like(&self, year: u16, month: BadiMonth, day: u16) -> Result<Self, BadiDateError> {
+        Self::new_with_rules(
+            year,
+            month,
+            day,
+            self.timezone,
+            self.coordinates,
+            self.calendar_rules,
+        )
+        .map(|date| date.with_day_boundary_policy(self.day_boundary_policy))
+    }
 }
 
 impl BadiDateLike for LocalBadiDate {
-    fn year(&self) -> u8 {
+    fn year(&self) -> u16 {
         self.year
     }
 
@@ -110,30 +191,34 @@ impl BadiDateLike for LocalBadiDate {
         self.day_of_year
     }
 
+    fn calendar_rules(&self) -> CalendarRules {
+        self.calendar_rules
+    }
+
     fn with_day(&self, day: u16) -> Result<LocalBadiDate, BadiDateError> {
-        Self::new(self.year, self.month, day, self.timezone, self.coordinates)
+        self.new_like(self.year, self.month, day)
     }
 
     fn with_ymd(
         &self,
-        year: u8,
+        year: u16,
         month: BadiMonth,
         day: u16,
     ) -> Result<LocalBadiDate, BadiDateError> {
-        Self::new(year, month, day, self.timezone, self.coordinates)
+        self.new_like(year, month, day)
     }
 
     fn with_month(&self, month: BadiMonth) -> Result<LocalBadiDate, BadiDateError> {
-        Self::new(self.year, month, self.day, self.timezone, self.coordinates)
+        self.new_like(self.year, month, self.day)
     }
 
-    fn with_year(&self, year: u8) -> Result<LocalBadiDate, BadiDateError> {
-        Self::new(year, self.month, self.day, self.timezone, self.coordinates)
+    fn with_year(&self, year: u16) -> Result<LocalBadiDate, BadiDateError> {
+        self.new_like(year, self.month, self.day)
     }
 
-    fn with_year_and_doy(&self, year: u8, day_of_year: u16) -> Result<Self, BadiDateError> {
-        let (month, day) = month_and_day_from_doy(year, day_of_year)?;
-        Self::new(year, month, day, self.timezone, self.coordinates)
+    fn with_year_and_doy(&self, year: u16, day_of_year: u16) -> Result<Self, BadiDateError> {
+        let (month, day) = month_and_day_from_doy(year, day_of_year, self.calendar_rules)?;
+        self.new_like(year, month, day)
     }
 }
 
@@ -145,6 +230,70 @@ impl LocalBadiDateLike for LocalBadiDate {
     fn coordinates(&self) -> Option<Coordinates> {
         self.coordinates
     }
+
+    fn day_boundary_policy(&self) -> DayBoundaryPolicy {
+        self.day_boundary_policy
+    }
 }
 
 impl HolyDayProviding for LocalBadiDate {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use chrono_tz::Tz;
+
+    use crate::{
+        BadiDateLike, BadiMonth, BahaiHolyDay, Coordinates, DayBoundaryPolicy, HolyDayProviding,
+        LocalBadiDate, LocalBadiDateLike, ToDateTime,
+    };
+
+    #[test]
+    fn serde_round_trip() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        for coordinates in [None, coords] {
+            for month in BadiMonth::iter() {
+                for day in [1, month.number_of_days(181)] {
+                    let badi_date =
+                        LocalBadiDate::new(181, month, day, denver, coordinates).unwrap();
+                    let json = serde_json::to_string(&badi_date).unwrap();
+                    let deserialized: LocalBadiDate = serde_json::from_str(&json).unwrap();
+                    assert_eq!(deserialized, badi_date);
+                    assert_eq!(deserialized.day_of_year(), badi_date.day_of_year());
+                    assert_eq!(deserialized.coordinates(), coordinates);
+                    assert_eq!(deserialized.start(), badi_date.start());
+                }
+            }
+        }
+
+        let naw_ruz: LocalBadiDate = serde_json::from_str(
+            r#"{"year":181,"month":"Baha","day":1,"timezone":"America/Denver","coordinates":null}"#,
+        )
+        .unwrap();
+        assert_eq!(naw_ruz.day_of_year(), 1);
+        assert_eq!(naw_ruz.holy_day(), Some(BahaiHolyDay::NawRuz));
+        assert!(serde_json::from_str::<LocalBadiDate>(
+            r#"{"year":181,"month":"AyyamIHa","day":5,"timezone":"America/Denver","coordinates":null}"#,
+        )
+        .is_err());
+
+        let seven = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        for policy in [
+            DayBoundaryPolicy::NauticalTwilight,
+            DayBoundaryPolicy::FixedTime(seven),
+            DayBoundaryPolicy::NearestLatitude,
+        ] {
+            let badi_date = naw_ruz.with_day_boundary_policy(policy);
+            let json = serde_json::to_string(&badi_date).unwrap();
+            let deserialized: LocalBadiDate = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized.day_boundary_policy(), policy);
+            assert_eq!(deserialized, badi_date);
+            assert_ne!(deserialized, naw_ruz);
+        }
+        assert_eq!(
+            serde_json::to_string(&DayBoundaryPolicy::FixedTime(seven)).unwrap(),
+            r#"{"FixedTime":"19:00:00"}"#
+        );
+    }
+}
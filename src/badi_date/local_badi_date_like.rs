@@ -1,6 +1,6 @@
 use chrono_tz::Tz;
 
-use crate::{BadiDateLike, Coordinates};
+use crate::{BadiDateLike, Coordinates, DayBoundaryPolicy};
 
 /// Provides localization attributes for [`LocalBadiDate`][`crate::LocalBadiDate`]
 pub trait LocalBadiDateLike: BadiDateLike {
@@ -10,4 +10,7 @@ pub trait LocalBadiDateLike: BadiDateLike {
     /// The WGS84 GPS coordinates from which sunset is calculated
     /// These MUST match the time zone or sunset times will be wrong!
     fn coordinates(&self) -> Option<Coordinates>;
+
+    /// The [`DayBoundaryPolicy`] determining when the day begins and ends (sunset by default)
+    fn day_boundary_policy(&self) -> DayBoundaryPolicy;
 }
@@ -10,52 +10,122 @@ pub use badi_date_like::*;
 mod local_badi_date_like;
 pub use local_badi_date_like::*;
 
+mod badi_weekday;
+pub use badi_weekday::*;
+
+mod vahid_year;
+pub use vahid_year::*;
+
+mod calendar_rules;
+pub use calendar_rules::*;
+
 mod coordinates;
 pub use coordinates::*;
 
+mod day_boundary;
+pub use day_boundary::*;
+
 mod from_datetime;
 pub use from_datetime::*;
 
 mod badi_date_ops;
 pub use badi_date_ops::*;
 
+mod badi_duration;
+pub use badi_duration::*;
+
+mod badi_days;
+pub use badi_days::*;
+
+mod badi_date_range;
+pub use badi_date_range::*;
+
+mod format;
+pub use format::BadiDelayedFormat;
+
+mod naive_date;
+
+mod parse;
+
 mod to_datetime;
 use serde::{Deserialize, Serialize};
 pub use to_datetime::*;
 
-mod util;
+pub(crate) mod util;
 
 use crate::{BadiDateError, HolyDayProviding};
+use parse::settings_suffix;
 use util::*;
 
 use std::fmt;
 
 /// A structure that holds a date in the Badí‘ (Bahá’í) calendar without time zone or location info
+/// (deserialization is validated like [`BadiDate::new_with_rules`])
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialOrd, PartialEq, Serialize)]
+#[serde(try_from = "RawBadiDate")]
 pub struct BadiDate {
-    year: u8,
+    year: u16,
     month: BadiMonth,
     day: u16,
     #[serde(skip)]
     day_of_year: u16,
+    #[serde(default)]
+    calendar_rules: CalendarRules,
+}
+
+/// Unvalidated fields of a [`BadiDate`] as deserialized
+#[derive(Deserialize)]
+struct RawBadiDate {
+    year: u16,
+    month: BadiMonth,
+    day: u16,
+    #[serde(default)]
+    calendar_rules: CalendarRules,
+}
+
+impl TryFrom<RawBadiDate> for BadiDate {
+    type Error = BadiDateError;
+
+    fn try_from(raw: RawBadiDate) -> Result<Self, Self::Error> {
+        Self::new_with_rules(raw.year, raw.month, raw.day, raw.calendar_rules)
+    }
 }
 
 impl BadiDate {
-    /// Create a new [`BadiDate`] given day, [`BadiMonth`], and year; checks for validity
-    pub fn new(year: u8, month: BadiMonth, day: u16) -> Result<Self, BadiDateError> {
-        validate(year, month, day)?;
-        let day_of_year = day_of_year(year, &month, day);
+    /// Create a new [`BadiDate`] given day, [`BadiMonth`], and year; checks for validity under
+    /// [`CalendarRules::default()`], which also applies to years before 172 B.E. (e.g. 180 B.E. has 4 days of
+    /// Ayyám-i-Há, not the 5 of the Western calendar then; see [`BadiDate::new_with_rules`])
+    pub fn new(year: u16, month: BadiMonth, day: u16) -> Result<Self, BadiDateError> {
+        Self::new_with_rules(year, month, day, CalendarRules::default())
+    }
+
+    /// Create a new [`BadiDate`] given day, [`BadiMonth`], year, and [`CalendarRules`]; checks for validity
+    pub fn new_with_rules(
+        year: u16,
+        month: BadiMonth,
+        day: u16,
+        calendar_rules: CalendarRules,
+    ) -> Result<Self, BadiDateError> {
+        validate(year, month, day, calendar_rules)?;
+        let day_of_year = day_of_year(year, &month, day, calendar_rules);
         Ok(Self {
             year,
             month,
             day,
             day_of_year,
+            calendar_rules,
         })
     }
+
+    /// Create a new [`BadiDate`] the given number of `days` since 1 Bahá 1 B.E. (day 0); checks for validity
+    /// (see [`BadiDateLike::to_epoch_days`])
+    pub fn from_epoch_days(days: i32) -> Result<Self, BadiDateError> {
+        Self::new(1, BadiMonth::first(), 1)?.with_epoch_days(days)
+    }
 }
 
 impl BadiDateLike for BadiDate {
-    fn year(&self) -> u8 {
+    fn year(&self) -> u16 {
         self.year
     }
 
@@ -71,44 +141,92 @@ impl BadiDateLike for BadiDate {
         self.day_of_year
     }
 
+    fn calendar_rules(&self) -> CalendarRules {
+        self.calendar_rules
+    }
+
     fn with_day(&self, day: u16) -> Result<BadiDate, BadiDateError> {
-        Self::new(self.year, self.month, day)
+        Self::new_with_rules(self.year, self.month, day, self.calendar_rules)
     }
 
     fn with_month(&self, month: BadiMonth) -> Result<BadiDate, BadiDateError> {
-        Self::new(self.year, month, self.day)
+        Self::new_with_rules(self.year, month, self.day, self.calendar_rules)
     }
 
-    fn with_year(&self, year: u8) -> Result<Self, BadiDateError> {
-        Self::new(year, self.month, self.day)
+    fn with_year(&self, year: u16) -> Result<Self, BadiDateError> {
+        Self::new_with_rules(year, self.month, self.day, self.calendar_rules)
     }
 
-    fn with_ymd(&self, year: u8, month: BadiMonth, day: u16) -> Result<BadiDate, BadiDateError> {
-        Self::new(year, month, day)
+    fn with_ymd(&self, year: u16, month: BadiMonth, day: u16) -> Result<BadiDate, BadiDateError> {
+        Self::new_with_rules(year, month, day, self.calendar_rules)
     }
 
-    fn with_year_and_doy(&self, year: u8, day_of_year: u16) -> Result<Self, BadiDateError> {
-        let (month, day) = match month_and_day_from_doy(year, day_of_year) {
-            Ok(result) => result,
-            Err(err) => return Err(err),
-        };
-        Self::new(year, month, day)
+    fn with_year_and_doy(&self, year: u16, day_of_year: u16) -> Result<Self, BadiDateError> {
+        let (month, day) = month_and_day_from_doy(year, day_of_year, self.calendar_rules)?;
+        Self::new_with_rules(year, month, day, self.calendar_rules)
     }
 }
 
 impl HolyDayProviding for BadiDate {}
 
+/// "year-month-day" (e.g. "182-01-01"), followed by the [`CalendarRules`] if they are not the default
+/// (e.g. `180-00-05 [WesternGregorianPinned]`), so that [`BadiDate::from_str`][`std::str::FromStr::from_str`]
+/// reads it back
 impl fmt::Display for BadiDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:0>3}-{:0>2}-{:0>2}",
+            "{:0>3}-{:0>2}-{:0>2}{}",
             self.year,
-            match self.month {
-                BadiMonth::Month(month) => month,
-                BadiMonth::AyyamIHa => 0,
-            },
+            self.month.number(),
             self.day,
+            settings_suffix(self.calendar_rules, DayBoundaryPolicy::default()),
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{BadiDate, BadiDateLike, BadiMonth, BahaiHolyDay, CalendarRules, HolyDayProviding};
+
+    #[test]
+    fn serde_round_trip() {
+        for calendar_rules in [
+            CalendarRules::Unified2015,
+            CalendarRules::WesternGregorianPinned,
+        ] {
+            for month in BadiMonth::iter() {
+                for day in [1, month.number_of_days_with_rules(180, calendar_rules)] {
+                    let badi_date =
+                        BadiDate::new_with_rules(180, month, day, calendar_rules).unwrap();
+                    let json = serde_json::to_string(&badi_date).unwrap();
+                    let deserialized: BadiDate = serde_json::from_str(&json).unwrap();
+                    assert_eq!(deserialized, badi_date);
+                    assert_eq!(deserialized.day_of_year(), badi_date.day_of_year());
+                }
+            }
+        }
+
+        let naw_ruz: BadiDate =
+            serde_json::from_str(r#"{"year":181,"month":"Baha","day":1}"#).unwrap();
+        assert_eq!(naw_ruz.day_of_year(), 1);
+        assert_eq!(naw_ruz.holy_day(), Some(BahaiHolyDay::NawRuz));
+        let legacy: BadiDate =
+            serde_json::from_str(r#"{"year":180,"month":"AyyamIHa","day":4}"#).unwrap();
+        assert_eq!(legacy, BadiDate::new(180, BadiMonth::AyyamIHa, 4).unwrap());
+        let legacy: BadiDate =
+            serde_json::from_str(r#"{"year":181,"month":{"Month":19},"day":2}"#).unwrap();
+        assert_eq!(legacy, BadiDate::new(181, BadiMonth::Ala, 2).unwrap());
+
+        for json in [
+            r#"{"year":0,"month":"Baha","day":1}"#,
+            r#"{"year":1001,"month":"Baha","day":1}"#,
+            r#"{"year":181,"month":"Baha","day":0}"#,
+            r#"{"year":181,"month":"Baha","day":20}"#,
+            r#"{"year":180,"month":"AyyamIHa","day":5}"#,
+            r#"{"year":181,"month":{"Month":20},"day":1}"#,
+        ] {
+            assert!(serde_json::from_str::<BadiDate>(json).is_err(), "{json}");
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+
+use crate::{BadiDate, BadiDateError, BadiDateLike, BadiMonth, CalendarRules};
+
+impl BadiDate {
+    /// Create a new [`BadiDate`] from the Gregorian [`NaiveDate`] on which most of the Badi day falls
+    /// (the Badi day starts at sunset on the previous Gregorian day) under the given [`CalendarRules`]
+    pub fn from_naive_date_with_rules(
+        date: NaiveDate,
+        calendar_rules: CalendarRules,
+    ) -> Result<Self, BadiDateError> {
+        let (year, day_of_year) = calendar_rules.year_and_day_of_year(date)?;
+        Self::new_with_rules(year, BadiMonth::first(), 1, calendar_rules)?
+            .with_year_and_doy(year, day_of_year)
+    }
+}
+
+/// The Gregorian date on which most of the Badi day falls (the Badi day starts at sunset on the previous Gregorian day)
+/// under the date's [`CalendarRules`] (before 172 B.E., often a day earlier under the default unified calendar than
+/// under the Western one)
+impl From<BadiDate> for NaiveDate {
+    fn from(badi_date: BadiDate) -> Self {
+        badi_date
+            .calendar_rules()
+            .gregorian_date(badi_date.year(), badi_date.day_of_year())
+    }
+}
+
+/// The [`BadiDate`] (under [`CalendarRules::default()`]) ending at sunset on the given Gregorian date;
+/// the unified calendar also applies before 172 B.E., so e.g. 20 March 1844 is 1 Bahá 1 B.E. (use
+/// [`BadiDate::from_naive_date_with_rules`] with [`CalendarRules::WesternGregorianPinned`] for the Western dates)
+impl TryFrom<NaiveDate> for BadiDate {
+    type Error = BadiDateError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Self::from_naive_date_with_rules(date, CalendarRules::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, NaiveDate};
+
+    use crate::{BadiDate, BadiDateLike, BadiDateOps, BadiMonth, CalendarRules};
+
+    #[test]
+    fn naive_date_conversion() {
+        for (year, month, day, badi_year, badi_month, badi_day) in [
+            // Naw-Rúz 1 B.E. is 20 March 1844 under the (proleptic) unified calendar
+            (1844, 3, 20, 1, BadiMonth::Baha, 1),
+            (2015, 3, 21, 172, BadiMonth::Baha, 1),
+            (2016, 3, 19, 172, BadiMonth::Ala, 19),
+            (2016, 3, 20, 173, BadiMonth::Baha, 1),
+            (2024, 2, 25, 180, BadiMonth::Mulk, 19),
+            (2024, 2, 26, 180, BadiMonth::AyyamIHa, 1),
+            (2024, 2, 29, 180, BadiMonth::AyyamIHa, 4),
+            (2024, 3, 1, 180, BadiMonth::Ala, 1),
+            (2024, 3, 20, 181, BadiMonth::Baha, 1),
+            (2024, 5, 23, 181, BadiMonth::Azamat, 8),
+            (2065, 3, 19, 221, BadiMonth::Ala, 19),
+            (2065, 3, 20, 222, BadiMonth::Baha, 1),
+        ] {
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            let badi_date = BadiDate::new(badi_year, badi_month, badi_day).unwrap();
+            assert_eq!(BadiDate::try_from(date).unwrap(), badi_date);
+            assert_eq!(NaiveDate::from(badi_date), date);
+        }
+
+        let mut date = NaiveDate::from_ymd_opt(2015, 3, 1).unwrap();
+        let mut badi_date = BadiDate::try_from(date).unwrap();
+        for _ in 0..3000 {
+            date = date + Days::new(1);
+            badi_date = badi_date.next_day();
+            assert_eq!(BadiDate::try_from(date).unwrap(), badi_date);
+            assert_eq!(NaiveDate::from(badi_date.clone()), date);
+        }
+
+        assert!(BadiDate::try_from(NaiveDate::from_ymd_opt(1844, 3, 19).unwrap()).is_err());
+        assert!(BadiDate::try_from(NaiveDate::from_ymd_opt(1000, 1, 1).unwrap()).is_err());
+        assert!(BadiDate::try_from(NaiveDate::from_ymd_opt(2844, 3, 21).unwrap()).is_err());
+        let last = BadiDate::try_from(NaiveDate::from_ymd_opt(2844, 3, 1).unwrap()).unwrap();
+        assert_eq!(last.year(), 1000);
+    }
+
+    #[test]
+    fn naive_date_conversion_with_rules() {
+        let rules = CalendarRules::WesternGregorianPinned;
+        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let badi_date = BadiDate::from_naive_date_with_rules(date, rules).unwrap();
+        assert_eq!(
+            badi_date,
+            BadiDate::new_with_rules(180, BadiMonth::Ala, 19, rules).unwrap()
+        );
+        assert_eq!(NaiveDate::from(badi_date), date);
+        let first = NaiveDate::from_ymd_opt(1844, 3, 21).unwrap();
+        assert_eq!(
+            BadiDate::from_naive_date_with_rules(first, rules).unwrap(),
+            BadiDate::new_with_rules(1, BadiMonth::Baha, 1, rules).unwrap()
+        );
+        assert!(BadiDate::from_naive_date_with_rules(first.pred_opt().unwrap(), rules).is_err());
+        let leap_day = NaiveDate::from_ymd_opt(1952, 2, 29).unwrap();
+        assert_eq!(
+            BadiDate::from_naive_date_with_rules(leap_day, rules).unwrap(),
+            BadiDate::new_with_rules(108, BadiMonth::AyyamIHa, 4, rules).unwrap()
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use std::str::FromStr;
+
+use chrono::NaiveTime;
+use chrono_tz::Tz;
+
+use crate::{
+    BadiDate, BadiDateParseError, BadiMonth, CalendarRules, Coordinates, DayBoundaryPolicy,
+    LocalBadiDate,
+};
+
+/// Locales whose month names are accepted when parsing
+const PARSE_LOCALES: [&str; 2] = ["tl", "en"];
+
+/// Folds a name for lenient comparison: lowercase, diacritics removed, and only ASCII letters and digits kept
+/// (e.g. "Ayyám-i-Há" and "ayyam i ha" both fold to "ayyamiha")
+pub(crate) fn fold(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'ā' => 'a',
+            'é' | 'è' | 'ê' | 'ë' | 'ē' => 'e',
+            'í' | 'ì' | 'î' | 'ï' | 'ī' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' | 'ō' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' | 'ū' => 'u',
+            'ḍ' | 'ḏ' => 'd',
+            'ḥ' => 'h',
+            'ṣ' => 's',
+            'ṭ' => 't',
+            'ẓ' | 'ẕ' => 'z',
+            c => c,
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+/// Accepts a month number ([1 - 19], or 0 for Ayyám-i-Há) or a transliterated or English month name
+/// (case and diacritics are ignored, e.g. "Bahá", "baha", "Splendour", "Ayyám-i-Há", "ayyamiha")
+impl FromStr for BadiMonth {
+    type Err = BadiDateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || BadiDateParseError::MonthUnknown(s.to_string());
+        if let Ok(number) = s.trim().parse::<u8>() {
+            return BadiMonth::try_from(number).map_err(|_| unknown());
+        }
+        let folded = fold(s);
+        if folded.is_empty() {
+            return Err(unknown());
+        }
+        BadiMonth::iter()
+            .find(|month| {
+                PARSE_LOCALES
+                    .iter()
+                    .any(|locale| fold(&month.name(locale)) == folded)
+            })
+            .ok_or_else(unknown)
+    }
+}
+
+/// Formats the non-default [`CalendarRules`] and [`DayBoundaryPolicy`] of a date as written after it by
+/// [`std::fmt::Display`] (e.g. " [WesternGregorianPinned, FixedTime(18:30:00)]"), or "" if both are the defaults
+pub(crate) fn settings_suffix(rules: CalendarRules, policy: DayBoundaryPolicy) -> String {
+    let mut settings = vec![];
+    if rules != CalendarRules::default() {
+        settings.push(format!("{rules:?}"));
+    }
+    match policy {
+        DayBoundaryPolicy::Sunset => {}
+        DayBoundaryPolicy::FixedTime(time) => settings.push(format!("FixedTime({time})")),
+        DayBoundaryPolicy::SunsetOrFixedTime(time) => {
+            settings.push(format!("SunsetOrFixedTime({time})"))
+        }
+        policy => settings.push(format!("{policy:?}")),
+    }
+    if settings.is_empty() {
+        String::default()
+    } else {
+        format!(" [{}]", settings.join(", "))
+    }
+}
+
+/// Splits the settings written by [`settings_suffix`] (if any) from the end of `s`
+fn split_settings(s: &str) -> Result<(&str, CalendarRules, DayBoundaryPolicy), BadiDateParseError> {
+    let s = s.trim();
+    let Some((rest, settings)) = s.strip_suffix(']').and_then(|s| s.rsplit_once('[')) else {
+        return Ok((s, CalendarRules::default(), DayBoundaryPolicy::default()));
+    };
+    let mut rules = CalendarRules::default();
+    let mut policy = DayBoundaryPolicy::default();
+    for setting in settings.split(',').map(str::trim) {
+        let invalid = || BadiDateParseError::FormatInvalid(setting.to_string());
+        let time = |name: &str| {
+            setting
+                .strip_prefix(name)
+                .and_then(|time| time.strip_prefix('('))
+                .and_then(|time| time.strip_suffix(')'))
+                .map(|time| time.parse::<NaiveTime>().map_err(|_| invalid()))
+        };
+        match setting {
+            "WesternGregorianPinned" => rules = CalendarRules::WesternGregorianPinned,
+            "Unified2015" => rules = CalendarRules::Unified2015,
+            "Sunset" => policy = DayBoundaryPolicy::Sunset,
+            "CivilTwilight" => policy = DayBoundaryPolicy::CivilTwilight,
+            "NauticalTwilight" => policy = DayBoundaryPolicy::NauticalTwilight,
+            "NearestLatitude" => policy = DayBoundaryPolicy::NearestLatitude,
+            _ => {
+                policy = if let Some(time) = time("FixedTime") {
+                    DayBoundaryPolicy::FixedTime(time?)
+                } else if let Some(time) = time("SunsetOrFixedTime") {
+                    DayBoundaryPolicy::SunsetOrFixedTime(time?)
+                } else {
+                    return Err(invalid());
+                }
+            }
+        }
+    }
+    Ok((rest.trim_end(), rules, policy))
+}
+
+/// Parses a year, month, and day from either the [`std::fmt::Display`] format of [`BadiDate`]
+/// ("182-01-01", month "00" for Ayyám-i-Há) or a named-month form ("1 Bahá 182", "4 Ayyám-i-Há 181")
+fn parse_ymd(s: &str) -> Result<(u16, BadiMonth, u16), BadiDateParseError> {
+    let invalid = || BadiDateParseError::FormatInvalid(s.to_string());
+    let s = s.trim();
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    if tokens.len() == 1 {
+        let parts: Vec<&str> = s.split('-').collect();
+        let [year, month, day] = parts[..] else {
+            return Err(invalid());
+        };
+        let year = year.parse::<u16>().map_err(|_| invalid())?;
+        let month = BadiMonth::try_from(month.parse::<u8>().map_err(|_| invalid())?)?;
+        let day = day.parse::<u16>().map_err(|_| invalid())?;
+        return Ok((year, month, day));
+    }
+    if tokens.len() < 3 {
+        return Err(invalid());
+    }
+    let last = tokens.len() - 1;
+    let day = tokens[0].parse::<u16>().map_err(|_| invalid())?;
+    let year = tokens[last].parse::<u16>().map_err(|_| invalid())?;
+    let month = tokens[1..last].join(" ").parse::<BadiMonth>()?;
+    Ok((year, month, day))
+}
+
+/// Accepts the [`std::fmt::Display`] format ("182-01-01") or a named-month form ("1 Bahá 182", "4 Ayyám-i-Há 181"),
+/// followed by the [`CalendarRules`] if they are not the default (e.g. `180-00-05 [WesternGregorianPinned]`)
+impl FromStr for BadiDate {
+    type Err = BadiDateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rules, policy) = split_settings(s)?;
+        if policy != DayBoundaryPolicy::default() {
+            return Err(BadiDateParseError::FormatInvalid(s.to_string()));
+        }
+        let (year, month, day) = parse_ymd(date)?;
+        Ok(BadiDate::new_with_rules(year, month, day, rules)?)
+    }
+}
+
+/// Accepts a [`BadiDate`] string (see [`BadiDate::from_str`]) followed by optional coordinates and an IANA time zone,
+/// as written by [`LocalBadiDate`]'s [`std::fmt::Display`] (e.g. "181-01-01 39.613319,-105.016647 America/Denver"
+/// or "1 Bahá 181 America/Denver"); the coordinates may also be an ISO 6709 point ("+39.6133-105.0166/"),
+/// and the date may be followed by its non-default [`CalendarRules`] and [`DayBoundaryPolicy`]
+/// (e.g. "181-01-01 [WesternGregorianPinned, NauticalTwilight] America/Denver")
+impl FromStr for LocalBadiDate {
+    type Err = BadiDateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BadiDateParseError::FormatInvalid(s.to_string());
+        let (rest, timezone) = s
+            .trim()
+            .rsplit_once(char::is_whitespace)
+            .ok_or_else(invalid)?;
+        let timezone = timezone
+            .parse::<Tz>()
+            .map_err(|_| BadiDateParseError::TimezoneUnknown(timezone.to_string()))?;
+        let rest = rest.trim_end();
+        let (date, coordinates) = match rest.rsplit_once(char::is_whitespace) {
+            Some((date, coordinates))
+                if coordinates.contains([',', '/']) && !coordinates.ends_with(']') =>
+            {
+                (
+                    date,
+                    Some(coordinates.parse::<Coordinates>().map_err(|_| {
+                        BadiDateParseError::CoordinatesInvalid(coordinates.to_string())
+                    })?),
+                )
+            }
+            _ => (rest, None),
+        };
+        let (date, rules, policy) = split_settings(date)?;
+        let (year, month, day) = parse_ymd(date)?;
+        Ok(
+            LocalBadiDate::new_with_rules(year, month, day, timezone, coordinates, rules)?
+                .with_day_boundary_policy(policy),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono_tz::Tz;
+
+    use chrono::NaiveTime;
+
+    use super::fold;
+    use crate::{
+        BadiDate, BadiDateParseError, BadiMonth, CalendarRules, Coordinates, DayBoundaryPolicy,
+        LocalBadiDate, LocalBadiDateLike,
+    };
+
+    #[test]
+    fn parse_month() {
+        assert_eq!("Bahá".parse::<BadiMonth>().unwrap(), BadiMonth::Baha);
+        assert_eq!("baha".parse::<BadiMonth>().unwrap(), BadiMonth::Baha);
+        assert_eq!("Splendour".parse::<BadiMonth>().unwrap(), BadiMonth::Baha);
+        assert_eq!("ʻAláʼ".parse::<BadiMonth>().unwrap(), BadiMonth::Ala);
+        assert_eq!("Ala".parse::<BadiMonth>().unwrap(), BadiMonth::Ala);
+        assert_eq!(
+            "Mashiyyat".parse::<BadiMonth>().unwrap(),
+            BadiMonth::Mashiyyat
+        );
+        assert_eq!(
+            "ayyam-i-ha".parse::<BadiMonth>().unwrap(),
+            BadiMonth::AyyamIHa
+        );
+        assert_eq!("0".parse::<BadiMonth>().unwrap(), BadiMonth::AyyamIHa);
+        assert_eq!("7".parse::<BadiMonth>().unwrap(), BadiMonth::Kalimat);
+        assert!(matches!(
+            "20".parse::<BadiMonth>(),
+            Err(BadiDateParseError::MonthUnknown(_))
+        ));
+        assert!("Dec".parse::<BadiMonth>().is_err());
+        assert!("".parse::<BadiMonth>().is_err());
+
+        let mut names = HashSet::new();
+        for month in BadiMonth::iter() {
+            for locale in ["tl", "en"] {
+                assert_eq!(month.name(locale).parse::<BadiMonth>().unwrap(), month);
+                assert!(names.insert(fold(&month.name(locale))));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_badi_date() {
+        for (s, year, month, day) in [
+            ("182-01-01", 182, BadiMonth::Baha, 1),
+            ("181-00-04", 181, BadiMonth::AyyamIHa, 4),
+            ("1-19-19", 1, BadiMonth::Ala, 19),
+            ("1 Bahá 182", 182, BadiMonth::Baha, 1),
+            ("4 Ayyám-i-Há 181", 181, BadiMonth::AyyamIHa, 4),
+            ("  7 Núr  181 ", 181, BadiMonth::Nur, 7),
+            ("19 Loftiness 180", 180, BadiMonth::Ala, 19),
+        ] {
+            let badi_date = BadiDate::new(year, month, day).unwrap();
+            assert_eq!(s.parse::<BadiDate>().unwrap(), badi_date);
+            assert_eq!(
+                badi_date.to_string().parse::<BadiDate>().unwrap(),
+                badi_date
+            );
+        }
+        for s in [
+            "",
+            "182",
+            "182-01",
+            "182-01-01-01",
+            "x-01-01",
+            "Bahá 182",
+            "1 Bahá",
+        ] {
+            assert!(matches!(
+                s.parse::<BadiDate>(),
+                Err(BadiDateParseError::FormatInvalid(_))
+            ));
+        }
+        assert!(matches!(
+            "1 Dec 182".parse::<BadiDate>(),
+            Err(BadiDateParseError::MonthUnknown(_))
+        ));
+        assert!(matches!(
+            "181-00-06".parse::<BadiDate>(),
+            Err(BadiDateParseError::DateInvalid(_))
+        ));
+        assert!(matches!(
+            "182-20-01".parse::<BadiDate>(),
+            Err(BadiDateParseError::DateInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn parse_local_badi_date() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        for coordinates in [
+            None,
+            coords,
+            Some(Coordinates::new(-5.5, 0.25).unwrap()),
+            Some(Coordinates::new(0., 0.).unwrap()),
+            Some(
+                Coordinates::new(-16.489689, -68.119294)
+                    .unwrap()
+                    .with_elevation(3640.)
+                    .unwrap(),
+            ),
+        ] {
+            for (year, month, day) in [
+                (181, BadiMonth::Baha, 1),
+                (180, BadiMonth::AyyamIHa, 4),
+                (181, BadiMonth::Ala, 19),
+            ] {
+                let badi_date = LocalBadiDate::new(year, month, day, denver, coordinates).unwrap();
+                let parsed = badi_date.to_string().parse::<LocalBadiDate>().unwrap();
+                assert_eq!(parsed, badi_date);
+                assert_eq!(parsed.coordinates(), coordinates);
+            }
+        }
+        let badi_date = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, coords).unwrap();
+        assert_eq!(
+            badi_date.to_string(),
+            "181-01-01 39.613319,-105.016647 America/Denver"
+        );
+        assert_eq!(
+            "1 Bahá 181 +39.613319-105.016647/ America/Denver"
+                .parse::<LocalBadiDate>()
+                .unwrap(),
+            badi_date
+        );
+        assert_eq!(
+            "1 Bahá 181 America/Denver"
+                .parse::<LocalBadiDate>()
+                .unwrap(),
+            LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap()
+        );
+        assert!(matches!(
+            "181-01-01 Mountain/Time".parse::<LocalBadiDate>(),
+            Err(BadiDateParseError::TimezoneUnknown(_))
+        ));
+        assert!(matches!(
+            "181-01-01".parse::<LocalBadiDate>(),
+            Err(BadiDateParseError::FormatInvalid(_))
+        ));
+        assert!(matches!(
+            "181-01-01 x,y America/Denver".parse::<LocalBadiDate>(),
+            Err(BadiDateParseError::CoordinatesInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rules_and_policy() {
+        let western = CalendarRules::WesternGregorianPinned;
+        // 180 B.E. has 5 days of Ayyám-i-Há only under the Western rules
+        let badi_date = BadiDate::new_with_rules(180, BadiMonth::AyyamIHa, 5, western).unwrap();
+        assert_eq!(badi_date.to_string(), "180-00-05 [WesternGregorianPinned]");
+        assert_eq!(
+            badi_date.to_string().parse::<BadiDate>().unwrap(),
+            badi_date
+        );
+        let badi_date = BadiDate::new_with_rules(181, BadiMonth::Baha, 1, western).unwrap();
+        assert_eq!(
+            badi_date.to_string().parse::<BadiDate>().unwrap(),
+            badi_date
+        );
+        assert_eq!(
+            "1 Bahá 181 [WesternGregorianPinned]"
+                .parse::<BadiDate>()
+                .unwrap(),
+            badi_date
+        );
+        assert_eq!(
+            "181-01-01 [Unified2015]".parse::<BadiDate>().unwrap(),
+            BadiDate::new(181, BadiMonth::Baha, 1).unwrap()
+        );
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        let seven = NaiveTime::from_hms_opt(19, 0, 0).unwrap();
+        for coordinates in [None, coords] {
+            for rules in [CalendarRules::Unified2015, western] {
+                for policy in [
+                    DayBoundaryPolicy::Sunset,
+                    DayBoundaryPolicy::CivilTwilight,
+                    DayBoundaryPolicy::NauticalTwilight,
+                    DayBoundaryPolicy::FixedTime(seven),
+                    DayBoundaryPolicy::SunsetOrFixedTime(seven),
+                    DayBoundaryPolicy::NearestLatitude,
+                ] {
+                    let badi_date = LocalBadiDate::new_with_rules(
+                        180,
+                        BadiMonth::AyyamIHa,
+                        4,
+                        denver,
+                        coordinates,
+                        rules,
+                    )
+                    .unwrap()
+                    .with_day_boundary_policy(policy);
+                    let parsed = badi_date.to_string().parse::<LocalBadiDate>().unwrap();
+                    assert_eq!(parsed, badi_date, "{badi_date}");
+                }
+            }
+        }
+        let badi_date =
+            LocalBadiDate::new_with_rules(181, BadiMonth::Baha, 1, denver, coords, western)
+                .unwrap()
+                .with_day_boundary_policy(DayBoundaryPolicy::FixedTime(seven));
+        assert_eq!(
+            badi_date.to_string(),
+            "181-01-01 [WesternGregorianPinned, FixedTime(19:00:00)] 39.613319,-105.016647 America/Denver"
+        );
+        // a BadiDate has no day boundary policy
+        for invalid in ["181-01-01 [Gregorian]", "181-01-01 [NauticalTwilight]"] {
+            assert!(
+                matches!(
+                    invalid.parse::<BadiDate>(),
+                    Err(BadiDateParseError::FormatInvalid(_))
+                ),
+                "{invalid}"
+            );
+        }
+        assert!(matches!(
+            "181-01-01 [FixedTime(25:00:00)] America/Denver".parse::<LocalBadiDate>(),
+            Err(BadiDateParseError::FormatInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn parse_keeps_coordinates_precision() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let precise = Coordinates::new(39.61331912345678, -105.01664798765432)
+            .unwrap()
+            .with_elevation(1609.3447)
+            .unwrap();
+        assert_eq!(precise.to_string().parse::<Coordinates>(), Ok(precise));
+        let badi_date = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, Some(precise)).unwrap();
+        let parsed = badi_date.to_string().parse::<LocalBadiDate>().unwrap();
+        assert_eq!(parsed.coordinates(), Some(precise));
+    }
+
+    #[test]
+    fn errors_box_with_source() {
+        fn parse(s: &str) -> Result<BadiDate, Box<dyn std::error::Error>> {
+            Ok(s.parse::<BadiDate>()?)
+        }
+        assert!(parse("181-01-01").is_ok());
+        let error = parse("181-01-20").unwrap_err();
+        let source = error.source().unwrap().to_string();
+        assert!(source.starts_with("BadiDateError:"), "{source}");
+        assert!(parse("181-01").unwrap_err().source().is_none());
+    }
+}
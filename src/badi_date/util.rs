@@ -1,16 +1,22 @@
 use chrono::{DateTime, Datelike, Days, TimeZone};
 use chrono_tz::Tz;
-use now::DateTimeNow;
 
-use crate::{statics::*, BadiDateError, BadiMonth, Coordinates};
+use crate::{
+    statics::*, BadiDateError, BadiMonth, CalendarRules, Coordinates, DayBoundary,
+    DayBoundaryPolicy,
+};
 
 /// Validate a Badi year, month, and day
-pub(crate) fn validate(year: u8, month: BadiMonth, day: u16) -> Result<(), BadiDateError> {
+pub(crate) fn validate(
+    year: u16,
+    month: BadiMonth,
+    day: u16,
+    rules: CalendarRules,
+) -> Result<(), BadiDateError> {
     if !(1..=LAST_YEAR_SUPPORTED).contains(&year) {
         return Err(BadiDateError::DateNotSupported);
     }
-    month.validate()?;
-    let max_day = month.number_of_days(year);
+    let max_day = month.number_of_days_with_rules(year, rules);
     if day < 1 || day > max_day {
         let err = BadiDateError::DayInvalid(month, day, max_day);
         return Err(err);
@@ -18,134 +24,94 @@ pub(crate) fn validate(year: u8, month: BadiMonth, day: u16) -> Result<(), BadiD
     Ok(())
 }
 
-/// Computes the sunset time of the current Badi year exactly at or before the given local datetime.
-pub(crate) fn get_sunset_of_last_naw_ruz(
+/// Computes the boundary starting Naw-Rúz of the current Badi year exactly at or before the given local datetime.
+pub(crate) fn get_start_of_last_naw_ruz(
     coordinates: &Option<Coordinates>,
+    policy: DayBoundaryPolicy,
     date: DateTime<Tz>,
-) -> DateTime<Tz> {
-    let badi_year = (date.year() - YEAR_ZERO_IN_GREGORIAN) as u8;
-    let specifics = YEAR_SPECIFICS.get(&badi_year);
-    let day = if let Some(specifics) = specifics {
-        if specifics.naw_ruz_on_march_21 {
-            21
-        } else {
-            20
-        }
-    } else {
-        21
-    };
-    let naw_ruz_date = date
-        .with_month(3)
-        .unwrap()
-        .with_day(day)
-        .unwrap()
-        .beginning_of_day();
-    let naw_ruz_sunset = get_last_sunset(coordinates, naw_ruz_date);
-    if naw_ruz_sunset <= date {
-        naw_ruz_sunset
+    rules: CalendarRules,
+) -> DayBoundary {
+    let year = (date.year() - YEAR_ZERO_IN_GREGORIAN) as u16;
+    let naw_ruz_start = get_start_of_naw_ruz(coordinates, policy, date.timezone(), year, rules);
+    if naw_ruz_start.moment <= date {
+        naw_ruz_start
     } else {
-        get_sunset_of_last_naw_ruz(coordinates, naw_ruz_date - Days::new(364))
+        get_start_of_naw_ruz(coordinates, policy, date.timezone(), year - 1, rules)
     }
 }
 
-// Computes the number of days in Ayyám-i-Há of the given Badi (B.E.) year
-pub(crate) fn get_number_of_ayyamiha_days(year: u8) -> u16 {
-    let specifics = YEAR_SPECIFICS.get(&year);
-    if let Some(specifics) = specifics {
-        if specifics.leapday {
-            5
-        } else {
-            4
-        }
-    } else {
-        let greg_year: i32 = i32::from(year) + YEAR_ONE_IN_GREGORIAN;
-        let is_leap_year = greg_year % 4 == 0 && greg_year % 100 != 0 || greg_year % 400 == 0;
-        if is_leap_year {
-            5
-        } else {
-            4
-        }
-    }
-}
-
-/// Computes the sunset occuring on the date of the passed local DateTime
-/// Passing `coordinates` as `None` will return `START_OF_DAY_FALLBACK` in `Tz` timezone
-pub(crate) fn get_sunset(coordinates: &Option<Coordinates>, date: DateTime<Tz>) -> DateTime<Tz> {
-    let fallback = date.with_time(*START_OF_DAY_FALLBACK).unwrap();
-    let Some(coordinates) = coordinates else {
-        return fallback;
-    };
-    let &Coordinates {
-        latitude,
-        longitude,
-    } = coordinates;
-    if !(-66. ..=66.).contains(&latitude) {
-        return fallback;
-    }
-    let (_, sunset_timestamp) =
-        sunrise::sunrise_sunset(latitude, longitude, date.year(), date.month(), date.day());
-    date.timezone().from_utc_datetime(
-        &DateTime::from_timestamp(sunset_timestamp, 0)
-            .unwrap()
-            .naive_utc(),
-    )
+/// Computes the boundary starting Naw-Rúz of the given Badi year in the given time zone
+pub(crate) fn get_start_of_naw_ruz(
+    coordinates: &Option<Coordinates>,
+    policy: DayBoundaryPolicy,
+    timezone: Tz,
+    year: u16,
+    rules: CalendarRules,
+) -> DayBoundary {
+    let eve = rules.naw_ruz_date(year).pred_opt().unwrap();
+    let eve = timezone
+        .from_local_datetime(&eve.and_hms_opt(12, 0, 0).unwrap())
+        .unwrap();
+    get_day_boundary(coordinates, policy, eve)
 }
 
-// Computes the next sunset exactly at or after the passed local DateTime
-pub(crate) fn get_next_sunset(
+/// Computes the day boundary (sunset under the default [`DayBoundaryPolicy`]) occuring on the date of the passed
+/// local DateTime; see [`DayBoundaryPolicy`] for its fallbacks
+pub(crate) fn get_day_boundary(
     coordinates: &Option<Coordinates>,
+    policy: DayBoundaryPolicy,
     date: DateTime<Tz>,
-) -> DateTime<Tz> {
-    let sunset = get_sunset(coordinates, date);
-    if date >= sunset {
-        return get_sunset(coordinates, date + Days::new(1));
-    }
-    sunset
+) -> DayBoundary {
+    policy.boundary(coordinates, date)
 }
 
-/// Computes the previous sunset before the passed local DateTime
-pub(crate) fn get_last_sunset(
+/// Computes the last day boundary exactly at or before the passed local DateTime
+pub(crate) fn get_last_day_boundary(
     coordinates: &Option<Coordinates>,
+    policy: DayBoundaryPolicy,
     date: DateTime<Tz>,
-) -> DateTime<Tz> {
-    let sunset = get_sunset(coordinates, date);
-    if date < sunset {
-        return get_sunset(coordinates, date - Days::new(1));
+) -> DayBoundary {
+    let mut day = date;
+    loop {
+        // the boundary computed for a date may fall after midnight (see `DayBoundaryPolicy::FixedTime`)
+        let boundary = get_day_boundary(coordinates, policy, day);
+        if boundary.moment <= date {
+            return boundary;
+        }
+        day = day - Days::new(1);
     }
-    sunset
 }
 
 pub(crate) fn month_and_day_from_doy(
-    year: u8,
+    year: u16,
     doy: u16,
+    rules: CalendarRules,
 ) -> Result<(BadiMonth, u16), BadiDateError> {
     if !(1..=LAST_YEAR_SUPPORTED).contains(&year) {
         return Err(BadiDateError::DateNotSupported);
     }
-    let ayyamiha_days = get_number_of_ayyamiha_days(year);
+    let ayyamiha_days = rules.number_of_ayyamiha_days(year);
     let doy_0 = doy - 1;
     if doy < AYYAMIHA_DAY_1 {
-        let month = (doy_0 / 19 + 1) as u8;
+        let month = BadiMonth::try_from((doy_0 / 19 + 1) as u8)?;
         let day = doy_0 % 19 + 1;
-        Ok((BadiMonth::Month(month), day))
+        Ok((month, day))
     } else if doy < AYYAMIHA_DAY_1 + ayyamiha_days {
         Ok((BadiMonth::AyyamIHa, doy - AYYAMIHA_DAY_0))
     } else {
         let day: u16 = doy - (AYYAMIHA_DAY_0 + ayyamiha_days);
-        Ok((BadiMonth::Month(19), day))
+        Ok((BadiMonth::Ala, day))
     }
 }
 
 // Computes the absolute 1-based day of the year given Badi year/month/day
-pub(crate) fn day_of_year(year: u8, month: &BadiMonth, day: u16) -> u16 {
+pub(crate) fn day_of_year(year: u16, month: &BadiMonth, day: u16, rules: CalendarRules) -> u16 {
     match *month {
-        BadiMonth::Month(month) => match month.cmp(&19) {
-            std::cmp::Ordering::Less => 19 * (month - 1) as u16 + day,
-            _ => {
-                let ayyamiha_days = get_number_of_ayyamiha_days(year);
-                AYYAMIHA_DAY_0 + ayyamiha_days + day
-            }
-        },
         BadiMonth::AyyamIHa => AYYAMIHA_DAY_0 + day,
+        BadiMonth::Ala => {
+            let ayyamiha_days = rules.number_of_ayyamiha_days(year);
+            AYYAMIHA_DAY_0 + ayyamiha_days + day
+        }
+        month => 19 * (month.number() - 1) as u16 + day,
     }
 }
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+
+use crate::{BadiDateError, LAST_YEAR_SUPPORTED};
+
+/// Number of years in a Váḥid
+pub const YEARS_IN_VAHID: u16 = 19;
+
+/// Number of years in a Kull-i-Shay (19 Váḥids)
+pub const YEARS_IN_KULL_I_SHAY: u16 = YEARS_IN_VAHID * YEARS_IN_VAHID;
+
+/// A Badí‘ year expressed within its 19-year Váḥid and 361-year Kull-i-Shay
+/// (deserialization is validated like [`VahidYear::from_parts`])
+///
+/// [`fmt::Display`] gives the long form, e.g. "Year Bahá of the 10th Váḥid of the 1st Kull-i-Shay"
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "RawVahidYear")]
+pub struct VahidYear {
+    kull_i_shay: u16,
+    vahid: u16,
+    year_in_vahid: u16,
+}
+
+/// Unvalidated fields of [`VahidYear`] as deserialized
+#[derive(Deserialize)]
+struct RawVahidYear {
+    kull_i_shay: u16,
+    vahid: u16,
+    year_in_vahid: u16,
+}
+
+impl TryFrom<RawVahidYear> for VahidYear {
+    type Error = BadiDateError;
+
+    fn try_from(raw: RawVahidYear) -> Result<Self, Self::Error> {
+        Self::from_parts(raw.kull_i_shay, raw.vahid, raw.year_in_vahid)
+    }
+}
+
+impl VahidYear {
+    /// Create a new [`VahidYear`] from a Bahá’í Era/Badi year (year 1 is the 1st year of the 1st Váḥid);
+    /// the year must be in the supported range [1 - 1000]
+    pub fn new(year: u16) -> Result<Self, BadiDateError> {
+        if !(1..=LAST_YEAR_SUPPORTED).contains(&year) {
+            return Err(BadiDateError::DateNotSupported);
+        }
+        let zero_based = year - 1;
+        Ok(Self {
+            kull_i_shay: zero_based / YEARS_IN_KULL_I_SHAY + 1,
+            vahid: zero_based % YEARS_IN_KULL_I_SHAY / YEARS_IN_VAHID + 1,
+            year_in_vahid: zero_based % YEARS_IN_VAHID + 1,
+        })
+    }
+
+    /// Create a new [`VahidYear`] from the Kull-i-Shay, the Váḥid within it [1 - 19], and the year within the Váḥid
+    /// [1 - 19]; the year must be in the supported range [1 - 1000]
+    pub fn from_parts(
+        kull_i_shay: u16,
+        vahid: u16,
+        year_in_vahid: u16,
+    ) -> Result<Self, BadiDateError> {
+        let cycle = 1..=YEARS_IN_VAHID;
+        if kull_i_shay < 1 || !cycle.contains(&vahid) || !cycle.contains(&year_in_vahid) {
+            return Err(BadiDateError::VahidYearInvalid(
+                kull_i_shay,
+                vahid,
+                year_in_vahid,
+            ));
+        }
+        let year = (kull_i_shay as u32 - 1) * YEARS_IN_KULL_I_SHAY as u32
+            + (vahid as u32 - 1) * YEARS_IN_VAHID as u32
+            + year_in_vahid as u32;
+        Self::new(
+            year.try_into()
+                .map_err(|_| BadiDateError::DateNotSupported)?,
+        )
+    }
+
+    /// The Kull-i-Shay [1 - ...] (the first Kull-i-Shay started in 1844)
+    pub fn kull_i_shay(&self) -> u16 {
+        self.kull_i_shay
+    }
+
+    /// The Váḥid within the Kull-i-Shay [1 - 19]
+    pub fn vahid(&self) -> u16 {
+        self.vahid
+    }
+
+    /// The year within the Váḥid [1 - 19]
+    pub fn year_in_vahid(&self) -> u16 {
+        self.year_in_vahid
+    }
+
+    /// The Bahá’í Era/Badi year
+    pub fn year(&self) -> u16 {
+        (self.kull_i_shay - 1) * YEARS_IN_KULL_I_SHAY
+            + (self.vahid - 1) * YEARS_IN_VAHID
+            + self.year_in_vahid
+    }
+
+    /// Get name of the year within its Váḥid in a locale (see /locales/app.yaml):
+    /// en: English
+    /// ar: Arabic
+    /// tl: English transliteration
+    /// extra: Additional meanings (English)
+    pub fn name(&self, locale: &str) -> String {
+        let year_key = format!("year.{}", self.year_in_vahid);
+        t!(&year_key, locale = locale).to_string()
+    }
+}
+
+impl TryFrom<u16> for VahidYear {
+    type Error = BadiDateError;
+
+    fn try_from(year: u16) -> Result<Self, Self::Error> {
+        Self::new(year)
+    }
+}
+
+impl fmt::Display for VahidYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Year {} of the {} Váḥid of the {} Kull-i-Shay",
+            self.name("tl"),
+            ordinal(self.vahid),
+            ordinal(self.kull_i_shay),
+        )
+    }
+}
+
+/// English ordinal of a number (1st, 2nd, 3rd, 4th, ..., 11th, 12th, 13th, ..., 21st)
+fn ordinal(number: u16) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{number}{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BadiDate, BadiDateLike, BadiMonth, VahidYear, LAST_YEAR_SUPPORTED};
+
+    #[test]
+    fn vahid_and_kull_i_shay() {
+        let badi = BadiDate::new(180, BadiMonth::Baha, 1).unwrap();
+        assert_eq!(badi.kull_i_shay(), 1);
+        assert_eq!(badi.vahid(), 10);
+        assert_eq!(badi.year_in_vahid(), 9);
+        assert_eq!(badi.year_name("tl"), "Bahá");
+        assert_eq!(badi.year_name("en"), "Splendour");
+        assert_eq!(
+            badi.vahid_year().to_string(),
+            "Year Bahá of the 10th Váḥid of the 1st Kull-i-Shay"
+        );
+
+        let first = VahidYear::new(1).unwrap();
+        assert_eq!(
+            (first.kull_i_shay(), first.vahid(), first.year_in_vahid()),
+            (1, 1, 1)
+        );
+        assert_eq!(first.name("tl"), "Alif");
+        let last_of_vahid = VahidYear::new(19).unwrap();
+        assert_eq!(
+            (last_of_vahid.vahid(), last_of_vahid.year_in_vahid()),
+            (1, 19)
+        );
+        assert_eq!(last_of_vahid.name("tl"), "Váḥid");
+        let last_of_kull_i_shay = VahidYear::new(361).unwrap();
+        assert_eq!(
+            last_of_kull_i_shay.to_string(),
+            "Year Váḥid of the 19th Váḥid of the 1st Kull-i-Shay"
+        );
+        assert_eq!(
+            VahidYear::new(362).unwrap().to_string(),
+            "Year Alif of the 1st Váḥid of the 2nd Kull-i-Shay"
+        );
+        assert_eq!(
+            VahidYear::new(361 + 19 + 4).unwrap().to_string(),
+            "Year Dál of the 2nd Váḥid of the 2nd Kull-i-Shay"
+        );
+        assert_eq!(
+            VahidYear::new(2 * 361 + 11 * 19 + 1).unwrap().to_string(),
+            "Year Alif of the 12th Váḥid of the 3rd Kull-i-Shay"
+        );
+
+        for year in 1..=LAST_YEAR_SUPPORTED {
+            let vahid_year = VahidYear::try_from(year).unwrap();
+            assert_eq!(vahid_year.year(), year);
+            assert!((1..=19).contains(&vahid_year.vahid()));
+            assert!((1..=19).contains(&vahid_year.year_in_vahid()));
+            assert_eq!(
+                VahidYear::from_parts(
+                    vahid_year.kull_i_shay(),
+                    vahid_year.vahid(),
+                    vahid_year.year_in_vahid()
+                )
+                .unwrap(),
+                vahid_year
+            );
+            assert!(!vahid_year.name("ar").starts_with("year."));
+        }
+    }
+
+    #[test]
+    fn invalid_vahid_years() {
+        assert!(VahidYear::new(0).is_err());
+        assert!(VahidYear::new(LAST_YEAR_SUPPORTED + 1).is_err());
+        for (kull_i_shay, vahid, year_in_vahid) in [
+            (0, 1, 1),
+            (1, 0, 1),
+            (1, 1, 0),
+            (1, 20, 1),
+            (1, 1, 20),
+            (3, 19, 19),
+        ] {
+            assert!(
+                VahidYear::from_parts(kull_i_shay, vahid, year_in_vahid).is_err(),
+                "{kull_i_shay} {vahid} {year_in_vahid}"
+            );
+        }
+
+        let json = r#"{"kull_i_shay":1,"vahid":10,"year_in_vahid":9}"#;
+        let vahid_year: VahidYear = serde_json::from_str(json).unwrap();
+        assert_eq!(vahid_year.year(), 180);
+        assert_eq!(serde_json::to_string(&vahid_year).unwrap(), json);
+        assert!(serde_json::from_str::<VahidYear>(
+            r#"{"kull_i_shay":1,"vahid":0,"year_in_vahid":9}"#
+        )
+        .is_err());
+    }
+}
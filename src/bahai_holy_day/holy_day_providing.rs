@@ -4,22 +4,23 @@ use crate::{BadiDateError, BadiDateLike, BahaiHolyDay};
 pub trait HolyDayProviding: BadiDateLike {
     /// Gets the holy day for `self.day` if it is a holy day
     fn holy_day(&self) -> Option<BahaiHolyDay> {
-        BahaiHolyDay::holy_days_for_year(self.year())
+        BahaiHolyDay::holy_days_for_year(self.year(), self.calendar_rules())
             .get(&self.day_of_year())
             .cloned()
     }
 
     /// Gets the next holy day, if within the supported date range
     fn next_holy_day(&self) -> Result<Self, BadiDateError> {
-        for year in [self.year(), self.year() + 1] {
+        for year in [self.year(), self.year().saturating_add(1)] {
             let after_day = if year == self.year() {
                 self.day_of_year()
             } else {
                 0
             };
-            if let Some((day_of_year, _holy_day)) = BahaiHolyDay::holy_days_for_year(year)
-                .into_iter()
-                .find(|(day_of_year, _)| *day_of_year > after_day)
+            if let Some((day_of_year, _holy_day)) =
+                BahaiHolyDay::holy_days_for_year(year, self.calendar_rules())
+                    .into_iter()
+                    .find(|(day_of_year, _)| *day_of_year > after_day)
             {
                 return self.with_year_and_doy(year, day_of_year);
             }
@@ -29,16 +30,16 @@ pub trait HolyDayProviding: BadiDateLike {
 
     /// Gets the previous holy day, if within the supported date range
     fn previous_holy_day(&self) -> Result<Self, BadiDateError> {
-        for year in [self.year(), self.year() - 1] {
+        for year in [self.year(), self.year().saturating_sub(1)] {
             let before_day = if year == self.year() {
                 self.day_of_year()
             } else {
                 366
             };
-            if let Some((day_of_year, _holy_day)) = BahaiHolyDay::holy_days_for_year(year)
-                .into_iter()
-                .filter(|(day_of_year, _)| *day_of_year < before_day)
-                .last()
+            if let Some((day_of_year, _holy_day)) =
+                BahaiHolyDay::holy_days_for_year(year, self.calendar_rules())
+                    .into_iter()
+                    .rfind(|(day_of_year, _)| *day_of_year < before_day)
             {
                 return self.with_year_and_doy(year, day_of_year);
             }
@@ -49,17 +50,38 @@ pub trait HolyDayProviding: BadiDateLike {
 
 #[cfg(test)]
 mod tests {
-    use crate::{BadiDate, BadiDateOps, BadiMonth, BahaiHolyDay, HolyDayProviding};
+    use crate::{
+        BadiDate, BadiDateLike, BadiDateOps, BadiMonth, BahaiHolyDay, HolyDayProviding,
+        LAST_YEAR_SUPPORTED,
+    };
+
+    #[test]
+    fn twin_holy_days_beyond_published_years() {
+        for year in (222..=LAST_YEAR_SUPPORTED).step_by(7) {
+            // 1 Mas͟híyyat precedes the earliest possible Birth of the Báb
+            let badi = BadiDate::new(year, BadiMonth::Mashiyyat, 1).unwrap();
+            let birth_of_bab: BadiDate = badi.next_holy_day().unwrap();
+            assert_eq!(birth_of_bab.holy_day(), Some(BahaiHolyDay::BirthOfTheBab));
+            assert_eq!(
+                birth_of_bab.day_of_year(),
+                BahaiHolyDay::BirthOfTheBab.day_of_year(year)
+            );
+            assert_eq!(
+                birth_of_bab.next_day().holy_day(),
+                Some(BahaiHolyDay::BirthOfBahaullah)
+            );
+        }
+    }
 
     #[test]
     fn add_subtract_next_previous() {
         // 2 Nur 181 B.E. / 5 June 2024
-        let badi = BadiDate::new(181, BadiMonth::Month(5), 2).unwrap();
+        let badi = BadiDate::new(181, BadiMonth::Nur, 2).unwrap();
         let next_holy_day: BadiDate = badi.next_holy_day().unwrap();
         assert_eq!(
             next_holy_day,
             // Martyrdom of the Báb
-            BadiDate::new(181, BadiMonth::Month(6), 17).unwrap(),
+            BadiDate::new(181, BadiMonth::Rahmat, 17).unwrap(),
         );
         assert_eq!(
             next_holy_day.holy_day(),
@@ -70,14 +92,14 @@ mod tests {
         assert_eq!(
             next_holy_day,
             // Birth of the Báb
-            BadiDate::new(181, BadiMonth::Month(12), 19).unwrap(),
+            BadiDate::new(181, BadiMonth::Ilm, 19).unwrap(),
         );
         assert_eq!(next_holy_day.holy_day(), Some(BahaiHolyDay::BirthOfTheBab));
         let next_holy_day: BadiDate = next_holy_day.next_holy_day().unwrap();
         assert_eq!(
             next_holy_day,
             // Birth of Bahá’u’lláh
-            BadiDate::new(181, BadiMonth::Month(13), 1).unwrap(),
+            BadiDate::new(181, BadiMonth::Qudrat, 1).unwrap(),
         );
         assert_eq!(
             next_holy_day.holy_day(),
@@ -93,19 +115,19 @@ mod tests {
         assert_eq!(
             next_holy_day,
             // Ascension of ‘Abdu’l-Bahá
-            BadiDate::new(181, BadiMonth::Month(14), 6).unwrap(),
+            BadiDate::new(181, BadiMonth::Qawl, 6).unwrap(),
         );
         let prev_holy_day = badi.previous_holy_day().unwrap();
         assert_eq!(
             prev_holy_day,
             // Ascension of Bahá’u’lláh
-            BadiDate::new(181, BadiMonth::Month(4), 13).unwrap(),
+            BadiDate::new(181, BadiMonth::Azamat, 13).unwrap(),
         );
         let prev_holy_day = prev_holy_day.previous_holy_day().unwrap();
         assert_eq!(
             prev_holy_day,
             // Declaration of the Báb
-            BadiDate::new(181, BadiMonth::Month(4), 8).unwrap(),
+            BadiDate::new(181, BadiMonth::Azamat, 8).unwrap(),
         );
 
         let badi = BadiDate::new(181, BadiMonth::AyyamIHa, 2).unwrap();
@@ -113,7 +135,7 @@ mod tests {
         assert_eq!(
             next_holy_day,
             // Naw-Rúz
-            BadiDate::new(182, BadiMonth::Month(1), 1).unwrap(),
+            BadiDate::new(182, BadiMonth::Baha, 1).unwrap(),
         );
         assert_eq!(next_holy_day.holy_day(), Some(BahaiHolyDay::NawRuz),);
 
@@ -121,7 +143,7 @@ mod tests {
         assert_eq!(
             previous_holy_day,
             // Ascension of ‘Abdu’l-Bahá
-            BadiDate::new(181, BadiMonth::Month(14), 6).unwrap(),
+            BadiDate::new(181, BadiMonth::Qawl, 6).unwrap(),
         );
         assert_eq!(
             previous_holy_day.holy_day(),
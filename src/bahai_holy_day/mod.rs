@@ -6,7 +6,7 @@ use std::collections::BTreeMap;
 
 use rust_i18n::t;
 
-use crate::{HOLY_DAYS_FALLBACK, YEAR_SPECIFICS};
+use crate::{CalendarRules, HOLY_DAYS_FALLBACK};
 
 /// List of the 11 Bahá’í Holy Days (9 on which work is to be suspended)
 /// See <https://www.bahai.org/action/devotional-life/calendar>
@@ -73,21 +73,15 @@ impl BahaiHolyDay {
     }
 
     /// Get the 1-based Badi day of the Badi year on which the holy day occurs in the given Badi `year`
-    pub fn day_of_year(&self, year: u8) -> u16 {
-        let day = *HOLY_DAYS_FALLBACK.get(self).unwrap();
-        if [BahaiHolyDay::BirthOfTheBab, BahaiHolyDay::BirthOfBahaullah].contains(self) {
-            return match YEAR_SPECIFICS.get(&year) {
-                Some(specifics) => {
-                    if self == &BahaiHolyDay::BirthOfTheBab {
-                        specifics.birth_of_bab
-                    } else {
-                        specifics.birth_of_bab + 1
-                    }
-                }
-                None => day,
-            };
-        }
-        day
+    /// (the Twin Holy Days follow the eighth new moon after Naw-Rúz, as seen from Ṭihrán)
+    pub fn day_of_year(&self, year: u16) -> u16 {
+        self.day_of_year_with_rules(year, CalendarRules::default())
+    }
+
+    /// Get the 1-based Badi day of the Badi year on which the holy day occurs in the given Badi `year`
+    /// under the given [`CalendarRules`]
+    pub fn day_of_year_with_rules(&self, year: u16, rules: CalendarRules) -> u16 {
+        rules.holy_day_of_year(*self, year)
     }
 
     /// Whether work is to be suspended on this holy day
@@ -99,21 +93,13 @@ impl BahaiHolyDay {
         .contains(self)
     }
 
-    pub(crate) fn holy_days_for_year(year: u8) -> BTreeMap<u16, BahaiHolyDay> {
-        let specifics: Option<&crate::YearSpecifics> = YEAR_SPECIFICS.get(&year);
+    pub(crate) fn holy_days_for_year(
+        year: u16,
+        rules: CalendarRules,
+    ) -> BTreeMap<u16, BahaiHolyDay> {
         HOLY_DAYS_FALLBACK
-            .iter()
-            .map(|(k, v)| {
-                let key = match specifics {
-                    Some(specifics) => match k {
-                        BahaiHolyDay::BirthOfTheBab => specifics.birth_of_bab,
-                        BahaiHolyDay::BirthOfBahaullah => specifics.birth_of_bab + 1,
-                        _ => *v,
-                    },
-                    None => *v,
-                };
-                (key, *k)
-            })
+            .keys()
+            .map(|holy_day| (holy_day.day_of_year_with_rules(year, rules), *holy_day))
             .collect()
     }
 }
@@ -7,10 +7,12 @@ use crate::{BadiMonth, LAST_YEAR_SUPPORTED};
 pub enum BadiDateError {
     /// The day number passed in for a given [`BadiMonth`] is invalid for that month
     DayInvalid(BadiMonth, u16, u16),
-    /// The [`BadiMonth`] itself is invalid (due to an invalid day number)
-    MonthInvalid(BadiMonth),
+    /// The month number is not in the range [0 - 19] (0 for Ayyám-i-Há; see [`BadiMonth::try_from`])
+    MonthInvalid(u8),
     /// The date passed in is not in the supported range
     DateNotSupported,
+    /// The Kull-i-Shay, Váḥid, and year in the Váḥid passed in do not form a valid [`VahidYear`][`crate::VahidYear`]
+    VahidYearInvalid(u16, u16, u16),
 }
 
 impl BadiDateError {
@@ -25,21 +27,24 @@ impl BadiDateError {
                     month.description()
                 )
             }
-            BadiDateError::MonthInvalid(month) => match month {
-                BadiMonth::Month(month) => {
-                    format!(
-                        "ERROR: BadiMonth::Month({}) is not in the range [1-19]",
-                        month
-                    )
-                }
-                BadiMonth::AyyamIHa => String::new(),
-            },
+            BadiDateError::MonthInvalid(month) => {
+                format!(
+                    "ERROR: Invalid Badi month: {} is not in the range [0-19] (0 for Ayyám-i-Há)",
+                    month
+                )
+            }
             BadiDateError::DateNotSupported => {
                 format!(
                     "The given date is not supported; year must be in the range [1-{}]",
                     LAST_YEAR_SUPPORTED
                 )
             }
+            BadiDateError::VahidYearInvalid(kull_i_shay, vahid, year_in_vahid) => {
+                format!(
+                    "ERROR: Invalid Váḥid year: Kull-i-Shay {} must be at least 1, and Váḥid {} and year {} in the range [1-19]",
+                    kull_i_shay, vahid, year_in_vahid
+                )
+            }
         }
     }
 }
@@ -49,3 +54,64 @@ impl Display for BadiDateError {
         write!(f, "BadiDateError: {}", self.message())
     }
 }
+
+impl std::error::Error for BadiDateError {}
+
+/// Error returned from trying to parse a [`BadiDate`][`crate::BadiDate`], [`LocalBadiDate`][`crate::LocalBadiDate`],
+/// or [`BadiMonth`] from a string
+#[derive(Debug)]
+pub enum BadiDateParseError {
+    /// The string is not in a supported format
+    FormatInvalid(String),
+    /// The month name or number is not recognized
+    MonthUnknown(String),
+    /// The time zone is not a known IANA time zone name
+    TimezoneUnknown(String),
+    /// The coordinates could not be parsed
+    CoordinatesInvalid(String),
+    /// The string was parsed but does not represent a valid date
+    DateInvalid(BadiDateError),
+}
+
+impl BadiDateParseError {
+    /// Message associated with the [`BadiDateParseError`]
+    pub fn message(&self) -> String {
+        match self {
+            BadiDateParseError::FormatInvalid(input) => format!(
+                "ERROR: \"{}\" is not in a supported format (e.g. \"182-01-01\" or \"1 Bahá 182\")",
+                input
+            ),
+            BadiDateParseError::MonthUnknown(input) => {
+                format!("ERROR: \"{}\" is not a known Badi month", input)
+            }
+            BadiDateParseError::TimezoneUnknown(input) => {
+                format!("ERROR: \"{}\" is not a known IANA time zone", input)
+            }
+            BadiDateParseError::CoordinatesInvalid(input) => {
+                format!("ERROR: \"{}\" are not valid coordinates", input)
+            }
+            BadiDateParseError::DateInvalid(error) => error.message(),
+        }
+    }
+}
+
+impl From<BadiDateError> for BadiDateParseError {
+    fn from(error: BadiDateError) -> Self {
+        BadiDateParseError::DateInvalid(error)
+    }
+}
+
+impl Display for BadiDateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BadiDateParseError: {}", self.message())
+    }
+}
+
+impl std::error::Error for BadiDateParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BadiDateParseError::DateInvalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -6,9 +6,9 @@
 //!
 //! ```
 //! use badi_date::{BadiDate, BadiMonth, BadiDateOps};
-//! let badi_date = BadiDate::new(181, BadiMonth::Month(19), 19).unwrap();
+//! let badi_date = BadiDate::new(181, BadiMonth::Ala, 19).unwrap();
 //! assert_eq!(
-//!     BadiDate::new(182, BadiMonth::Month(1), 1).unwrap(),
+//!     BadiDate::new(182, BadiMonth::Baha, 1).unwrap(),
 //!     badi_date.add_days(1),
 //! );
 //! ```
@@ -24,7 +24,7 @@
 //! let badi_date = LocalBadiDate::from_datetime(date, coords).unwrap();
 //! assert_eq!(
 //!     badi_date,
-//!     LocalBadiDate::new(180, BadiMonth::Month(19), 19, denver, coords).unwrap(),
+//!     LocalBadiDate::new(180, BadiMonth::Ala, 19, denver, coords).unwrap(),
 //! );
 //! ```
 //!
@@ -37,7 +37,7 @@
 //! let date = denver.with_ymd_and_hms(2024, 3, 19, 18, 0, 0).unwrap();
 //! let badi_date = LocalBadiDate::from_datetime(date, None).unwrap();
 //! assert_eq!(
-//!     LocalBadiDate::new(181, BadiMonth::Month(1), 1, denver, None).unwrap(),
+//!     LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap(),
 //!     badi_date,
 //! );
 //! ```
@@ -48,6 +48,9 @@ extern crate rust_i18n;
 
 rust_i18n::i18n!("locales");
 
+mod astronomy;
+pub use astronomy::{HorizonCriteria, Refraction, SolarDisc, SolarEvent};
+
 mod statics;
 use statics::*;
 
@@ -59,3 +62,5 @@ pub use badi_date::*;
 
 mod bahai_holy_day;
 pub use bahai_holy_day::*;
+
+pub mod serde;
@@ -0,0 +1,456 @@
+//! Compact serde representations of [`BadiDate`] and [`LocalBadiDate`] to opt into with `#[serde(with = "...")]`
+//! (like [`chrono::serde`]), e.g. for size-sensitive payloads:
+//!
+//! | Module               | [`BadiDate`]  | [`LocalBadiDate`]                                                |
+//! |----------------------|---------------|------------------------------------------------------------------|
+//! | [`string`]           | `"181-01-01"` | `"181-01-01 America/Denver"` (see [`LocalBadiDate`]'s `Display`) |
+//! | [`tuple`][mod@tuple] | `[181,1,1]`   | `[181,1,1,"America/Denver",[39.613319,-105.016647]]`             |
+//! | [`epoch_days`]       | `65744`       | `[65744,"America/Denver",null]`                                  |
+//!
+//! Months are numbered [1 - 19] with 0 for Ayyám-i-Há, [`Coordinates`] are `[latitude, longitude]`
+//! (or `[latitude, longitude, elevation]`, or `null`), and
+//! epoch days are counted from 1 Bahá 1 B.E. (see [`BadiDateLike::to_epoch_days`]). Each module has an `option`
+//! submodule for `Option<BadiDate>` and `Option<LocalBadiDate>`.
+//!
+//! The [`CalendarRules`] and [`DayBoundaryPolicy`] are not included: dates are deserialized under
+//! [`CalendarRules::default()`] and [`DayBoundaryPolicy::default()`], and serializing a date with others fails.
+//!
+//! ```
+//! use badi_date::{BadiDate, BadiMonth};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Event {
+//!     #[serde(with = "badi_date::serde::string")]
+//!     date: BadiDate,
+//!     #[serde(with = "badi_date::serde::epoch_days::option")]
+//!     until: Option<BadiDate>,
+//! }
+//!
+//! let event = Event {
+//!     date: BadiDate::new(181, BadiMonth::Baha, 1).unwrap(),
+//!     until: None,
+//! };
+//! let json = serde_json::to_string(&event).unwrap();
+//! assert_eq!(json, r#"{"date":"181-01-01","until":null}"#);
+//! assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+use ::serde::{de::DeserializeOwned, ser, Serialize};
+use chrono_tz::Tz;
+
+use crate::{
+    BadiDate, BadiDateLike, BadiDateParseError, BadiMonth, CalendarRules, Coordinates,
+    CoordinatesError, DayBoundaryPolicy, LocalBadiDate, LocalBadiDateLike,
+};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::BadiDate {}
+    impl Sealed for crate::LocalBadiDate {}
+}
+
+/// A [`BadiDateLike`] with compact serde representations (implemented for [`BadiDate`] and [`LocalBadiDate`])
+pub trait CompactSerde:
+    BadiDateLike + Display + FromStr<Err = BadiDateParseError> + sealed::Sealed
+{
+    #[doc(hidden)]
+    type Tuple: Serialize + DeserializeOwned;
+    #[doc(hidden)]
+    type EpochDays: Serialize + DeserializeOwned;
+    #[doc(hidden)]
+    fn to_tuple(&self) -> Self::Tuple;
+    #[doc(hidden)]
+    fn from_tuple(tuple: Self::Tuple) -> Result<Self, String>;
+    #[doc(hidden)]
+    fn to_epoch_days_repr(&self) -> Self::EpochDays;
+    #[doc(hidden)]
+    fn from_epoch_days_repr(repr: Self::EpochDays) -> Result<Self, String>;
+    #[doc(hidden)]
+    fn has_default_day_boundary_policy(&self) -> bool {
+        true
+    }
+}
+
+/// `[latitude, longitude]`, or `[latitude, longitude, elevation]` if there is an elevation
+type CoordinatesRepr = Option<Vec<f64>>;
+
+fn coordinates_to_repr(coordinates: Option<Coordinates>) -> CoordinatesRepr {
+    coordinates.map(|coordinates| {
+        let mut repr = vec![coordinates.latitude, coordinates.longitude];
+        repr.extend(coordinates.elevation);
+        repr
+    })
+}
+
+fn coordinates_from_repr(repr: CoordinatesRepr) -> Result<Option<Coordinates>, String> {
+    repr.map(|repr| match repr[..] {
+        [latitude, longitude] => Coordinates::new(latitude, longitude),
+        [latitude, longitude, elevation] => {
+            Coordinates::new(latitude, longitude)?.with_elevation(elevation)
+        }
+        _ => Err(CoordinatesError::FormatInvalid),
+    })
+    .transpose()
+    .map_err(|err| err.message())
+}
+
+impl CompactSerde for BadiDate {
+    type Tuple = (u16, u8, u16);
+    type EpochDays = i32;
+
+    fn to_tuple(&self) -> Self::Tuple {
+        (self.year(), self.month().number(), self.day())
+    }
+
+    fn from_tuple((year, month, day): Self::Tuple) -> Result<Self, String> {
+        BadiMonth::try_from(month)
+            .and_then(|month| BadiDate::new(year, month, day))
+            .map_err(|err| err.message())
+    }
+
+    fn to_epoch_days_repr(&self) -> Self::EpochDays {
+        self.to_epoch_days()
+    }
+
+    fn from_epoch_days_repr(days: Self::EpochDays) -> Result<Self, String> {
+        BadiDate::from_epoch_days(days).map_err(|err| err.message())
+    }
+}
+
+impl CompactSerde for LocalBadiDate {
+    type Tuple = (u16, u8, u16, Tz, CoordinatesRepr);
+    type EpochDays = (i32, Tz, CoordinatesRepr);
+
+    fn to_tuple(&self) -> Self::Tuple {
+        (
+            self.year(),
+            self.month().number(),
+            self.day(),
+            self.timezone(),
+            coordinates_to_repr(self.coordinates()),
+        )
+    }
+
+    fn from_tuple((year, month, day, timezone, coordinates): Self::Tuple) -> Result<Self, String> {
+        let coordinates = coordinates_from_repr(coordinates)?;
+        BadiMonth::try_from(month)
+            .and_then(|month| LocalBadiDate::new(year, month, day, timezone, coordinates))
+            .map_err(|err| err.message())
+    }
+
+    fn to_epoch_days_repr(&self) -> Self::EpochDays {
+        (
+            self.to_epoch_days(),
+            self.timezone(),
+            coordinates_to_repr(self.coordinates()),
+        )
+    }
+
+    fn from_epoch_days_repr(
+        (days, timezone, coordinates): Self::EpochDays,
+    ) -> Result<Self, String> {
+        let coordinates = coordinates_from_repr(coordinates)?;
+        LocalBadiDate::new(1, BadiMonth::first(), 1, timezone, coordinates)
+            .and_then(|date| date.with_epoch_days(days))
+            .map_err(|err| err.message())
+    }
+
+    fn has_default_day_boundary_policy(&self) -> bool {
+        self.day_boundary_policy() == DayBoundaryPolicy::default()
+    }
+}
+
+/// Fails if `date` is not under [`CalendarRules::default()`] and [`DayBoundaryPolicy::default()`],
+/// which are assumed when deserializing
+fn check_defaults<T: CompactSerde, E: ser::Error>(date: &T) -> Result<(), E> {
+    if date.calendar_rules() != CalendarRules::default() {
+        return Err(E::custom(format!(
+            "{:?} dates cannot be serialized compactly",
+            date.calendar_rules()
+        )));
+    }
+    if !date.has_default_day_boundary_policy() {
+        return Err(E::custom(
+            "dates with a day boundary policy other than sunset cannot be serialized compactly",
+        ));
+    }
+    Ok(())
+}
+
+/// Generates the `option` submodule of a representation module from its `serialize` and `deserialize`
+macro_rules! option_module {
+    ($name:literal) => {
+        #[doc = concat!("`Option` of the [", $name, "][`super`] representation")]
+        pub mod option {
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::serde::CompactSerde;
+
+            struct Ser<'a, T>(&'a T);
+
+            impl<T: CompactSerde> Serialize for Ser<'_, T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(self.0, serializer)
+                }
+            }
+
+            struct De<T>(T);
+
+            impl<'de, T: CompactSerde> Deserialize<'de> for De<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(De)
+                }
+            }
+
+            /// Serialize an `Option` of a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`]
+            pub fn serialize<T: CompactSerde, S: Serializer>(
+                date: &Option<T>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                date.as_ref().map(Ser).serialize(serializer)
+            }
+
+            /// Deserialize an `Option` of a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`]
+            pub fn deserialize<'de, T: CompactSerde, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<T>, D::Error> {
+                Ok(Option::<De<T>>::deserialize(deserializer)?.map(|date| date.0))
+            }
+        }
+    };
+}
+
+/// The `Display` string (e.g. `"181-01-01"` or `"181-01-01 America/Denver"`)
+pub mod string {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::{check_defaults, CompactSerde};
+
+    /// Serialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] as a string
+    pub fn serialize<T: CompactSerde, S: Serializer>(
+        date: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        check_defaults(date)?;
+        serializer.collect_str(date)
+    }
+
+    /// Deserialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] from a string
+    pub fn deserialize<'de, T: CompactSerde, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+
+    option_module!("string");
+}
+
+/// A tuple of year, month number, and day (e.g. `[181,1,1]`), followed by the time zone and coordinates of a
+/// [`LocalBadiDate`][`crate::LocalBadiDate`]
+pub mod tuple {
+    use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{check_defaults, CompactSerde};
+
+    /// Serialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] as a tuple
+    pub fn serialize<T: CompactSerde, S: Serializer>(
+        date: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        check_defaults(date)?;
+        date.to_tuple().serialize(serializer)
+    }
+
+    /// Deserialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] from a tuple
+    pub fn deserialize<'de, T: CompactSerde, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::from_tuple(T::Tuple::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+
+    option_module!("tuple");
+}
+
+/// The number of days since 1 Bahá 1 B.E. (e.g. `65744`), in a tuple with the time zone and coordinates of a
+/// [`LocalBadiDate`][`crate::LocalBadiDate`]
+pub mod epoch_days {
+    use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{check_defaults, CompactSerde};
+
+    /// Serialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] as epoch days
+    pub fn serialize<T: CompactSerde, S: Serializer>(
+        date: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        check_defaults(date)?;
+        date.to_epoch_days_repr().serialize(serializer)
+    }
+
+    /// Deserialize a [`BadiDate`][`crate::BadiDate`] or [`LocalBadiDate`][`crate::LocalBadiDate`] from epoch days
+    pub fn deserialize<'de, T: CompactSerde, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::from_epoch_days_repr(T::EpochDays::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+
+    option_module!("epoch_days");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use ::serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use chrono_tz::Tz;
+
+    use crate::{
+        BadiDate, BadiMonth, CalendarRules, Coordinates, DayBoundaryPolicy, LocalBadiDate,
+        LocalBadiDateLike,
+    };
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Compact<T: super::CompactSerde> {
+        #[serde(with = "crate::serde::string")]
+        string: T,
+        #[serde(with = "crate::serde::tuple")]
+        tuple: T,
+        #[serde(with = "crate::serde::epoch_days")]
+        epoch_days: T,
+        #[serde(with = "crate::serde::string::option")]
+        string_option: Option<T>,
+        #[serde(with = "crate::serde::tuple::option")]
+        tuple_option: Option<T>,
+        #[serde(with = "crate::serde::epoch_days::option")]
+        epoch_days_option: Option<T>,
+    }
+
+    fn round_trip<T>(date: T) -> String
+    where
+        T: super::CompactSerde + Serialize + DeserializeOwned + Debug + PartialEq,
+    {
+        let compact = Compact {
+            string: date.clone(),
+            tuple: date.clone(),
+            epoch_days: date.clone(),
+            string_option: Some(date.clone()),
+            tuple_option: None,
+            epoch_days_option: Some(date),
+        };
+        let json = serde_json::to_string(&compact).unwrap();
+        assert_eq!(serde_json::from_str::<Compact<T>>(&json).unwrap(), compact);
+        json
+    }
+
+    #[test]
+    fn badi_date() {
+        let naw_ruz = BadiDate::new(181, BadiMonth::Baha, 1).unwrap();
+        assert_eq!(
+            round_trip(naw_ruz.clone()),
+            r#"{"string":"181-01-01","tuple":[181,1,1],"epoch_days":65744,"string_option":"181-01-01","tuple_option":null,"epoch_days_option":65744}"#
+        );
+        for month in BadiMonth::iter() {
+            round_trip(BadiDate::new(180, month, month.number_of_days(180)).unwrap());
+        }
+        assert_eq!(
+            serde_json::from_str::<Compact<BadiDate>>(
+                r#"{"string":"1 Bahá 181","tuple":[180,0,4],"epoch_days":0,"string_option":null,"tuple_option":[1,19,19],"epoch_days_option":null}"#
+            )
+            .unwrap(),
+            Compact {
+                string: naw_ruz,
+                tuple: BadiDate::new(180, BadiMonth::AyyamIHa, 4).unwrap(),
+                epoch_days: BadiDate::new(1, BadiMonth::Baha, 1).unwrap(),
+                string_option: None,
+                tuple_option: Some(BadiDate::new(1, BadiMonth::Ala, 19).unwrap()),
+                epoch_days_option: None,
+            }
+        );
+    }
+
+    #[test]
+    fn local_badi_date() {
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let coords = Some(Coordinates::new(39.613319, -105.016647).unwrap());
+        let naw_ruz = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap();
+        assert_eq!(
+            round_trip(naw_ruz),
+            r#"{"string":"181-01-01 America/Denver","tuple":[181,1,1,"America/Denver",null],"epoch_days":[65744,"America/Denver",null],"string_option":"181-01-01 America/Denver","tuple_option":null,"epoch_days_option":[65744,"America/Denver",null]}"#
+        );
+        for month in BadiMonth::iter() {
+            round_trip(LocalBadiDate::new(180, month, 1, denver, coords).unwrap());
+        }
+
+        let elevated = coords.map(|coords| coords.with_elevation(1609.).unwrap());
+        let json =
+            round_trip(LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, elevated).unwrap());
+        assert!(
+            json.contains(r#""tuple":[181,1,1,"America/Denver",[39.613319,-105.016647,1609.0]]"#),
+            "{json}"
+        );
+        let compact: Compact<LocalBadiDate> = serde_json::from_str(&json).unwrap();
+        for date in [compact.string, compact.tuple, compact.epoch_days] {
+            assert_eq!(date.coordinates(), elevated);
+        }
+
+        let precise = Some(Coordinates::new(39.61331912345678, -105.01664798765432).unwrap());
+        let json =
+            round_trip(LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, precise).unwrap());
+        let compact: Compact<LocalBadiDate> = serde_json::from_str(&json).unwrap();
+        for date in [compact.string, compact.tuple, compact.epoch_days] {
+            assert_eq!(date.coordinates(), precise);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        #[derive(Deserialize, Serialize)]
+        struct Tuple {
+            #[serde(with = "crate::serde::tuple")]
+            date: BadiDate,
+        }
+        for json in [
+            r#"{"date":[181,20,1]}"#,
+            r#"{"date":[180,0,5]}"#,
+            r#"{"date":[181,1]}"#,
+            r#"{"date":"181-01-01"}"#,
+        ] {
+            assert!(serde_json::from_str::<Tuple>(json).is_err(), "{json}");
+        }
+        #[derive(Deserialize, Serialize)]
+        struct LocalTuple {
+            #[serde(with = "crate::serde::tuple")]
+            date: LocalBadiDate,
+        }
+        for json in [
+            r#"{"date":[181,1,1,"America/Denver",[39.6]]}"#,
+            r#"{"date":[181,1,1,"America/Denver",[39.6,-105.0,1609,1]]}"#,
+            r#"{"date":[181,1,1,"America/Denver",[39.6,-105.0,10000]]}"#,
+        ] {
+            assert!(serde_json::from_str::<LocalTuple>(json).is_err(), "{json}");
+        }
+        let western = Tuple {
+            date: BadiDate::new_with_rules(
+                181,
+                BadiMonth::Baha,
+                1,
+                CalendarRules::WesternGregorianPinned,
+            )
+            .unwrap(),
+        };
+        assert!(serde_json::to_string(&western).is_err());
+        let denver: Tz = "America/Denver".parse().unwrap();
+        let date = LocalBadiDate::new(181, BadiMonth::Baha, 1, denver, None).unwrap();
+        assert!(serde_json::to_string(&LocalTuple { date: date.clone() }).is_ok());
+        let twilight = LocalTuple {
+            date: date.with_day_boundary_policy(DayBoundaryPolicy::CivilTwilight),
+        };
+        assert!(serde_json::to_string(&twilight).is_err());
+    }
+}
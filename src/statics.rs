@@ -1,39 +1,21 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
-use chrono::{DateTime, NaiveTime, TimeZone};
-use chrono_tz::{Asia::Tehran, Tz};
+use chrono::NaiveTime;
 use lazy_static::lazy_static;
 
 use crate::BahaiHolyDay;
 
 pub(crate) const YEAR_ONE_IN_GREGORIAN: i32 = 1844;
 pub(crate) const YEAR_ZERO_IN_GREGORIAN: i32 = YEAR_ONE_IN_GREGORIAN - 1;
-pub(crate) const LAST_YEAR_SUPPORTED: u8 = 221;
+pub(crate) const LAST_YEAR_SUPPORTED: u16 = 1000;
 pub(crate) const LAST_GREGORIAN_YEAR_SUPPORTED: i32 =
     YEAR_ONE_IN_GREGORIAN + LAST_YEAR_SUPPORTED as i32;
 pub(crate) const AYYAMIHA_DAY_1: u16 = 343;
 pub(crate) const AYYAMIHA_DAY_0: u16 = AYYAMIHA_DAY_1 - 1;
 
-#[derive(Debug)]
-pub(crate) struct YearSpecifics {
-    pub(crate) leapday: bool,
-    pub(crate) naw_ruz_on_march_21: bool,
-    #[allow(dead_code)]
-    pub(crate) birth_of_bab: u16,
-}
-
-impl YearSpecifics {
-    fn new(birth_of_bab: u16, leapday: bool, naw_ruz_on_march_21: bool) -> YearSpecifics {
-        YearSpecifics {
-            leapday,
-            naw_ruz_on_march_21,
-            birth_of_bab,
-        }
-    }
-}
-
 lazy_static! {
     // https://github.com/Soroosh/badi_date/blob/main/lib/bahai_holyday.dart
+    // (the Twin Holy Days are computed; their days here are the pre-2015 Western dates)
     pub(crate) static ref HOLY_DAYS_FALLBACK: BTreeMap<BahaiHolyDay, u16> = {
         let mut map = BTreeMap::new();
         map.insert(BahaiHolyDay::NawRuz, 1);
@@ -51,79 +33,146 @@ lazy_static! {
     };
     pub(crate) static ref START_OF_DAY_FALLBACK: NaiveTime =
         NaiveTime::from_hms_opt(18, 0, 0).unwrap();
-    pub(crate) static ref YEAR_SPECIFICS: HashMap<u8, YearSpecifics> = {
-        let mut map = HashMap::new();
-        map.insert(172, YearSpecifics::new(238, false, true));
-        map.insert(173, YearSpecifics::new(227, false, false));
-        map.insert(174, YearSpecifics::new(216, true, false));
-        map.insert(175, YearSpecifics::new(234, false, true));
-        map.insert(176, YearSpecifics::new(223, false, true));
-        map.insert(177, YearSpecifics::new(213, false, false));
-        map.insert(178, YearSpecifics::new(232, true, false));
-        map.insert(179, YearSpecifics::new(220, false, true));
-        map.insert(180, YearSpecifics::new(210, false, true));
-        map.insert(181, YearSpecifics::new(228, false, false));
-        map.insert(182, YearSpecifics::new(217, true, false));
-        map.insert(183, YearSpecifics::new(235, false, true));
-        map.insert(184, YearSpecifics::new(224, false, true));
-        map.insert(185, YearSpecifics::new(214, false, false));
-        map.insert(186, YearSpecifics::new(233, false, false));
-        map.insert(187, YearSpecifics::new(223, true, false));
-        map.insert(188, YearSpecifics::new(211, false, true));
-        map.insert(189, YearSpecifics::new(230, false, false));
-        map.insert(190, YearSpecifics::new(238, false, false));
-        map.insert(191, YearSpecifics::new(238, true, false));
-        map.insert(192, YearSpecifics::new(226, false, true));
-        map.insert(193, YearSpecifics::new(215, false, false));
-        map.insert(194, YearSpecifics::new(234, false, false));
-        map.insert(195, YearSpecifics::new(224, true, false));
-        map.insert(196, YearSpecifics::new(213, false, true));
-        map.insert(197, YearSpecifics::new(232, false, false));
-        map.insert(198, YearSpecifics::new(221, false, false));
-        map.insert(199, YearSpecifics::new(210, true, false));
-        map.insert(200, YearSpecifics::new(228, false, true));
-        map.insert(201, YearSpecifics::new(217, false, false));
-        map.insert(202, YearSpecifics::new(236, false, false));
-        map.insert(203, YearSpecifics::new(225, true, false));
-        map.insert(204, YearSpecifics::new(214, false, true));
-        map.insert(205, YearSpecifics::new(233, false, false));
-        map.insert(206, YearSpecifics::new(223, false, false));
-        map.insert(207, YearSpecifics::new(212, true, false));
-        map.insert(208, YearSpecifics::new(230, false, true));
-        map.insert(209, YearSpecifics::new(219, false, false));
-        map.insert(210, YearSpecifics::new(237, false, false));
-        map.insert(211, YearSpecifics::new(227, true, false));
-        map.insert(212, YearSpecifics::new(215, false, true));
-        map.insert(213, YearSpecifics::new(234, false, false));
-        map.insert(214, YearSpecifics::new(224, false, false));
-        map.insert(215, YearSpecifics::new(213, false, false));
-        map.insert(216, YearSpecifics::new(232, true, false));
-        map.insert(217, YearSpecifics::new(220, false, false));
-        map.insert(218, YearSpecifics::new(209, false, false));
-        map.insert(219, YearSpecifics::new(228, false, false));
-        map.insert(220, YearSpecifics::new(218, true, false));
-        map.insert(221, YearSpecifics::new(236, false, false));
-        map
-    };
-    pub(crate) static ref FIRST_GREGORIAN_DATE_SUPPORTED: DateTime<Tz> = Tehran
-        .with_ymd_and_hms(YEAR_ONE_IN_GREGORIAN, 3, 21, 0, 0, 0)
-        .unwrap();
-    pub(crate) static ref LAST_GREGORIAN_DATE_SUPPORTED: DateTime<Tz> = Tehran
-        .with_ymd_and_hms(LAST_GREGORIAN_YEAR_SUPPORTED, 3, 19, 0, 0, 0)
-        .unwrap();
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, HashMap};
+
+    use chrono::Datelike;
+    use lazy_static::lazy_static;
+
+    use crate::{BahaiHolyDay, CalendarRules, LAST_YEAR_SUPPORTED};
+
+    /// Published data for B.E. 172...221 which the astronomical calculations must reproduce; the calculations, not this
+    /// table, are the source of every date (including the `NaiveDate` conversions), so all years are treated alike
+    #[derive(Debug)]
+    struct YearSpecifics {
+        leapday: bool,
+        naw_ruz_on_march_21: bool,
+        birth_of_bab: u16,
+    }
+
+    impl YearSpecifics {
+        fn new(birth_of_bab: u16, leapday: bool, naw_ruz_on_march_21: bool) -> YearSpecifics {
+            YearSpecifics {
+                leapday,
+                naw_ruz_on_march_21,
+                birth_of_bab,
+            }
+        }
+    }
 
-    use crate::{YearSpecifics, YEAR_SPECIFICS};
+    lazy_static! {
+        static ref YEAR_SPECIFICS: HashMap<u16, YearSpecifics> = {
+            let mut map = HashMap::new();
+            map.insert(172, YearSpecifics::new(238, false, true));
+            map.insert(173, YearSpecifics::new(227, false, false));
+            map.insert(174, YearSpecifics::new(216, true, false));
+            map.insert(175, YearSpecifics::new(234, false, true));
+            map.insert(176, YearSpecifics::new(223, false, true));
+            map.insert(177, YearSpecifics::new(213, false, false));
+            map.insert(178, YearSpecifics::new(232, true, false));
+            map.insert(179, YearSpecifics::new(220, false, true));
+            map.insert(180, YearSpecifics::new(210, false, true));
+            map.insert(181, YearSpecifics::new(228, false, false));
+            map.insert(182, YearSpecifics::new(217, true, false));
+            map.insert(183, YearSpecifics::new(235, false, true));
+            map.insert(184, YearSpecifics::new(224, false, true));
+            map.insert(185, YearSpecifics::new(214, false, false));
+            map.insert(186, YearSpecifics::new(233, false, false));
+            map.insert(187, YearSpecifics::new(223, true, false));
+            map.insert(188, YearSpecifics::new(211, false, true));
+            map.insert(189, YearSpecifics::new(230, false, false));
+            map.insert(190, YearSpecifics::new(238, false, false));
+            map.insert(191, YearSpecifics::new(238, true, false));
+            map.insert(192, YearSpecifics::new(226, false, true));
+            map.insert(193, YearSpecifics::new(215, false, false));
+            map.insert(194, YearSpecifics::new(234, false, false));
+            map.insert(195, YearSpecifics::new(224, true, false));
+            map.insert(196, YearSpecifics::new(213, false, true));
+            map.insert(197, YearSpecifics::new(232, false, false));
+            map.insert(198, YearSpecifics::new(221, false, false));
+            map.insert(199, YearSpecifics::new(210, true, false));
+            map.insert(200, YearSpecifics::new(228, false, true));
+            map.insert(201, YearSpecifics::new(217, false, false));
+            map.insert(202, YearSpecifics::new(236, false, false));
+            map.insert(203, YearSpecifics::new(225, true, false));
+            map.insert(204, YearSpecifics::new(214, false, true));
+            map.insert(205, YearSpecifics::new(233, false, false));
+            map.insert(206, YearSpecifics::new(223, false, false));
+            map.insert(207, YearSpecifics::new(212, true, false));
+            map.insert(208, YearSpecifics::new(230, false, true));
+            map.insert(209, YearSpecifics::new(219, false, false));
+            map.insert(210, YearSpecifics::new(237, false, false));
+            map.insert(211, YearSpecifics::new(227, true, false));
+            map.insert(212, YearSpecifics::new(215, false, true));
+            map.insert(213, YearSpecifics::new(234, false, false));
+            map.insert(214, YearSpecifics::new(224, false, false));
+            map.insert(215, YearSpecifics::new(213, false, false));
+            map.insert(216, YearSpecifics::new(232, true, false));
+            map.insert(217, YearSpecifics::new(220, false, false));
+            map.insert(218, YearSpecifics::new(209, false, false));
+            map.insert(219, YearSpecifics::new(228, false, false));
+            map.insert(220, YearSpecifics::new(218, true, false));
+            map.insert(221, YearSpecifics::new(236, false, false));
+            map
+        };
+    }
+
+    #[test]
+    fn computed_naw_ruz_and_leapday_in_supported_range() {
+        for year in 1..=LAST_YEAR_SUPPORTED {
+            let naw_ruz = CalendarRules::Unified2015.naw_ruz_date(year);
+            assert_eq!(naw_ruz.month(), 3, "Naw-Rúz of {year}: {naw_ruz}");
+            assert!(
+                (19..=22).contains(&naw_ruz.day()),
+                "Naw-Rúz of {year}: {naw_ruz}"
+            );
+            assert!((4..=5).contains(&CalendarRules::Unified2015.number_of_ayyamiha_days(year)));
+        }
+    }
+
+    #[test]
+    fn computed_naw_ruz_and_leapday_match_year_specifics() {
+        for (year, specifics) in YEAR_SPECIFICS.iter() {
+            let naw_ruz = CalendarRules::Unified2015.naw_ruz_date(*year);
+            assert_eq!(
+                naw_ruz.day() == 21,
+                specifics.naw_ruz_on_march_21,
+                "Naw-Rúz of {year}: {naw_ruz}"
+            );
+            assert_eq!(
+                CalendarRules::Unified2015.number_of_ayyamiha_days(*year) == 5,
+                specifics.leapday,
+                "leap day of {year}"
+            );
+        }
+    }
+
+    #[test]
+    fn computed_twin_holy_days_match_year_specifics() {
+        for (year, specifics) in YEAR_SPECIFICS.iter() {
+            let birth_of_bab =
+                CalendarRules::Unified2015.holy_day_of_year(BahaiHolyDay::BirthOfTheBab, *year);
+            if *year == 190 {
+                // The source table repeats 238 (the value of 191) for 190, which is not the day after
+                // any new moon; the eighth new moon after Naw-Rúz is on 23 October 2033 (day 218).
+                assert_eq!(birth_of_bab, 219);
+                continue;
+            }
+            assert_eq!(
+                birth_of_bab, specifics.birth_of_bab,
+                "Birth of the Báb in {year}"
+            );
+        }
+    }
 
     #[test]
     fn year_specifics() {
         let mut dart_code = String::new();
 
-        let mut sorted_map: BTreeMap<u8, &YearSpecifics> = BTreeMap::new();
+        let mut sorted_map: BTreeMap<u16, &YearSpecifics> = BTreeMap::new();
         for (year, specifics) in YEAR_SPECIFICS.iter() {
             sorted_map.insert(*year, specifics);
         }